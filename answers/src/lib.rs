@@ -0,0 +1,55 @@
+use std::error::Error;
+use std::result;
+
+pub type Result<T> = result::Result<T, Box<dyn Error>>;
+
+/// Runs one day's solution for `part` (1 or 2) against the puzzle `input`, formatting the answer
+/// the way that day's binary prints it.
+pub fn solve(day: u32, part: u32, input: &str) -> Result<String> {
+    Ok(match (day, part) {
+        (1, 1) => day1::solve_part1_from_str(input)?.to_string(),
+        (1, 2) => day1::solve_part2_from_str(input)?.to_string(),
+        (2, 1) => day2::solve_part1_from_str(input).to_string(),
+        (2, 2) => day2::solve_part2_from_str(input).to_string(),
+        (3, 1) => day3::solve_part1_from_str(input)?.to_string(),
+        (3, 2) => day3::solve_part2_from_str(input)?.to_string(),
+        (4, 1) => day4::solve_part1_from_str(input)?.to_string(),
+        (4, 2) => day4::solve_part2_from_str(input)?.to_string(),
+        (5, 1) => day5::solve_part1_from_str(input)?.to_string(),
+        (5, 2) => day5::solve_part2_from_str(input)?.to_string(),
+        (6, 1) => day6::solve_part1_from_str(input)?.to_string(),
+        (6, 2) => day6::solve_part2_from_str(input)?.to_string(),
+        (7, 1) => day7::solve_part1_from_str(input)?.to_string(),
+        (7, 2) => day7::solve_part2_from_str(input)?.to_string(),
+        (8, 1) => day8::solve_part1_from_str(input)?.to_string(),
+        (8, 2) => day8::solve_part2_from_str(input)?,
+        (9, 1) => day9::solve_part1_from_str(input)?.to_string(),
+        (9, 2) => day9::solve_part2_from_str(input)?.to_string(),
+        (10, 1) => day10::solve_part1_from_str(input)?.to_string(),
+        (10, 2) => day10::solve_part2_from_str(input)?.to_string(),
+        (11, 1) => day11::solve_part1_from_str(input)?.to_string(),
+        (11, 2) => day11::solve_part2_from_str(input)?,
+        (12, 1) => day12::solve_part1_from_str(input)?.to_string(),
+        (12, 2) => day12::solve_part2_from_str(input)?.to_string(),
+        (13, 1) => day13::solve_part1_from_str(input)?.to_string(),
+        (13, 2) => day13::solve_part2_from_str(input)?.to_string(),
+        (14, 1) => day14::solve_part1_from_str(input)?.to_string(),
+        (14, 2) => day14::solve_part2_from_str(input)?.to_string(),
+        (15, 1) => day15::solve_part1_from_str(input)?.to_string(),
+        (15, 2) => day15::solve_part2_from_str(input)?.to_string(),
+        (16, 1) => day16::solve_part1_from_str(input)?,
+        (16, 2) => day16::solve_part2_from_str(input)?,
+        (17, 1) => day17::solve_part1_from_str(input)?.to_string(),
+        (17, 2) => day17::solve_part2_from_str(input)?.to_string(),
+        (22, 1) => day22::solve_part1_from_str(input)?.to_string(),
+        (22, 2) => day22::solve_part2_from_str(input)?.to_string(),
+        (23, 1) => day23::solve_part1_from_str(input)?.to_string(),
+        (23, 2) => day23::solve_part2_from_str(input)?.to_string(),
+        _ => {
+            return Err(From::from(format!(
+                "No solution for day {} part {}",
+                day, part
+            )))
+        }
+    })
+}
@@ -0,0 +1,54 @@
+use std::collections::BTreeMap;
+use std::fs;
+
+use serde::Deserialize;
+
+use answers::solve;
+
+const EXPECTED_ANSWERS: &str = "expected_answers.toml";
+
+#[derive(Deserialize)]
+struct Answer {
+    /// Relative to the day's directory. Days without an input file leave this out.
+    input: Option<String>,
+    #[serde(default)]
+    real_input: bool,
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+#[test]
+fn matches_known_answers() {
+    let answers: BTreeMap<String, Vec<Answer>> =
+        toml::from_str(&fs::read_to_string(EXPECTED_ANSWERS).unwrap()).unwrap();
+
+    let mut failures = vec![];
+    for (day_name, day_answers) in answers.iter() {
+        let day: u32 = day_name.trim_start_matches("day").parse().unwrap();
+        for answer in day_answers.iter() {
+            if answer.real_input && !cfg!(feature = "real_input") {
+                continue;
+            }
+            let input = match &answer.input {
+                Some(path) => fs::read_to_string(format!("../{}/{}", day_name, path)).unwrap(),
+                None => String::new(),
+            };
+            let label = answer.input.as_deref().unwrap_or("built-in input");
+            for (part, expected) in [(1, &answer.part1), (2, &answer.part2)].iter() {
+                if let Some(expected) = expected {
+                    match solve(day, *part, &input) {
+                        // the ASCII-art answers are compared without surrounding blank lines
+                        Ok(actual) if actual.trim() == expected.trim() => {}
+                        Ok(actual) => failures.push(format!(
+                            "{} part {} ({}): expected {}, got {}",
+                            day_name, part, label, expected, actual
+                        )),
+                        Err(err) => failures
+                            .push(format!("{} part {} ({}): {}", day_name, part, label, err)),
+                    }
+                }
+            }
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
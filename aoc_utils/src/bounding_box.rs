@@ -0,0 +1,111 @@
+use num::PrimInt;
+
+use crate::Point2D;
+
+/// Smallest axis-aligned box containing every included point. Both corners are inclusive.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BoundingBox<T: Copy + Ord> {
+    min: Point2D<T>,
+    max: Point2D<T>,
+}
+
+impl<T: PrimInt> BoundingBox<T> {
+    /// An empty box that contains no points until one is included.
+    pub fn new() -> BoundingBox<T> {
+        BoundingBox {
+            min: Point2D::new(T::max_value(), T::max_value()),
+            max: Point2D::new(T::min_value(), T::min_value()),
+        }
+    }
+
+    pub fn from_points(points: impl Iterator<Item = Point2D<T>>) -> Option<BoundingBox<T>> {
+        let mut bounding_box = BoundingBox::new();
+        for point in points {
+            bounding_box.include(&point);
+        }
+        if bounding_box.is_empty() {
+            None
+        } else {
+            Some(bounding_box)
+        }
+    }
+
+    pub fn include(&mut self, point: &Point2D<T>) {
+        self.min.x = self.min.x.min(point.x);
+        self.min.y = self.min.y.min(point.y);
+        self.max.x = self.max.x.max(point.x);
+        self.max.y = self.max.y.max(point.y);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y
+    }
+
+    pub fn min(&self) -> Point2D<T> {
+        self.min
+    }
+
+    pub fn max(&self) -> Point2D<T> {
+        self.max
+    }
+
+    pub fn width(&self) -> T {
+        if self.is_empty() {
+            return T::zero();
+        }
+        self.max.x - self.min.x + T::one()
+    }
+
+    pub fn height(&self) -> T {
+        if self.is_empty() {
+            return T::zero();
+        }
+        self.max.y - self.min.y + T::one()
+    }
+
+    pub fn contains(&self, point: &Point2D<T>) -> bool {
+        self.min.x <= point.x
+            && point.x <= self.max.x
+            && self.min.y <= point.y
+            && point.y <= self.max.y
+    }
+}
+
+impl<T: PrimInt> Default for BoundingBox<T> {
+    fn default() -> BoundingBox<T> {
+        BoundingBox::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn includes_points() {
+        let mut bounding_box = BoundingBox::new();
+        assert!(bounding_box.is_empty());
+        assert_eq!(bounding_box.width(), 0);
+        assert!(!bounding_box.contains(&Point2D::new(0, 0)));
+
+        bounding_box.include(&Point2D::new(2, -1));
+        assert_eq!((bounding_box.width(), bounding_box.height()), (1, 1));
+        bounding_box.include(&Point2D::new(-3, 4));
+        assert_eq!(bounding_box.min(), Point2D::new(-3, -1));
+        assert_eq!(bounding_box.max(), Point2D::new(2, 4));
+        assert_eq!((bounding_box.width(), bounding_box.height()), (6, 6));
+        assert!(bounding_box.contains(&Point2D::new(0, 0)));
+        assert!(bounding_box.contains(&Point2D::new(2, 4)));
+        assert!(!bounding_box.contains(&Point2D::new(3, 0)));
+    }
+
+    #[test]
+    fn builds_from_points() {
+        assert_eq!(BoundingBox::<i64>::from_points(vec![].into_iter()), None);
+        let bounding_box =
+            BoundingBox::from_points(vec![Point2D::new(1usize, 5), Point2D::new(4, 2)].into_iter())
+                .unwrap();
+        assert_eq!(bounding_box.min(), Point2D::new(1, 2));
+        assert_eq!(bounding_box.max(), Point2D::new(4, 5));
+    }
+}
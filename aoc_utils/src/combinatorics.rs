@@ -0,0 +1,141 @@
+struct Permutations<T> {
+    items: Vec<T>,
+    /// Heap's algorithm's per-level swap counters.
+    counters: Vec<usize>,
+    level: usize,
+    started: bool,
+}
+
+impl<T: Clone> Iterator for Permutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if !self.started {
+            self.started = true;
+            return Some(self.items.clone());
+        }
+        while self.level < self.items.len() {
+            if self.counters[self.level] < self.level {
+                if self.level.is_multiple_of(2) {
+                    self.items.swap(0, self.level);
+                } else {
+                    self.items.swap(self.counters[self.level], self.level);
+                }
+                self.counters[self.level] += 1;
+                self.level = 1;
+                return Some(self.items.clone());
+            }
+            self.counters[self.level] = 0;
+            self.level += 1;
+        }
+        None
+    }
+}
+
+/// Every ordering of `items`, generated by swapping one pair at a time with Heap's algorithm.
+pub fn permutations<T: Clone>(items: &[T]) -> impl Iterator<Item = Vec<T>> {
+    Permutations {
+        items: items.to_vec(),
+        counters: vec![0; items.len()],
+        level: 1,
+        started: false,
+    }
+}
+
+struct Combinations<T> {
+    items: Vec<T>,
+    /// Indices into `items` of the next combination, or `None` once all have been produced.
+    indices: Option<Vec<usize>>,
+}
+
+impl<T: Clone> Iterator for Combinations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        let indices = self.indices.as_mut()?;
+        let combination = indices.iter().map(|&i| self.items[i].clone()).collect();
+
+        // advance the rightmost index that still has room, resetting every index after it
+        let n = self.items.len();
+        let k = indices.len();
+        match (0..k).rev().find(|&i| indices[i] < n - k + i) {
+            Some(i) => {
+                indices[i] += 1;
+                for j in i + 1..k {
+                    indices[j] = indices[j - 1] + 1;
+                }
+            }
+            None => self.indices = None,
+        }
+        Some(combination)
+    }
+}
+
+/// Every way of choosing `k` of `items`, in the order they appear in `items`.
+pub fn combinations<T: Clone>(items: &[T], k: usize) -> impl Iterator<Item = Vec<T>> {
+    Combinations {
+        items: items.to_vec(),
+        indices: if k <= items.len() {
+            Some((0..k).collect())
+        } else {
+            None
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn generates_permutations() {
+        assert_eq!(permutations(&[1, 2, 3]).count(), 6);
+        let unique: HashSet<Vec<i32>> = permutations(&[1, 2, 3]).collect();
+        assert_eq!(unique.len(), 6);
+        for permutation in [
+            [1, 2, 3],
+            [1, 3, 2],
+            [2, 1, 3],
+            [2, 3, 1],
+            [3, 1, 2],
+            [3, 2, 1],
+        ]
+        .iter()
+        {
+            assert!(unique.contains(permutation.as_slice()));
+        }
+
+        assert_eq!(permutations(&[0, 1, 2, 3, 4]).count(), 120);
+        assert_eq!(
+            permutations(&[0, 1, 2, 3, 4]).collect::<HashSet<_>>().len(),
+            120
+        );
+        assert_eq!(permutations(&['a']).collect::<Vec<_>>(), vec![vec!['a']]);
+        assert_eq!(permutations::<u8>(&[]).count(), 1);
+    }
+
+    #[test]
+    fn generates_combinations() {
+        assert_eq!(combinations(&[1, 2, 3, 4], 2).count(), 6);
+        assert_eq!(
+            combinations(&[1, 2, 3, 4], 2).collect::<Vec<_>>(),
+            vec![
+                vec![1, 2],
+                vec![1, 3],
+                vec![1, 4],
+                vec![2, 3],
+                vec![2, 4],
+                vec![3, 4]
+            ]
+        );
+        assert_eq!(combinations(&[1, 2, 3, 4], 4).count(), 1);
+        assert_eq!(
+            combinations(&[1, 2, 3, 4], 0).collect::<Vec<_>>(),
+            vec![vec![]]
+        );
+        assert_eq!(combinations(&[1, 2, 3, 4], 5).count(), 0);
+        assert_eq!(combinations(&[0; 10], 3).count(), 120);
+    }
+}
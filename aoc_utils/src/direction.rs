@@ -0,0 +1,105 @@
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Direction4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction4 {
+    pub fn turn_left(self) -> Direction4 {
+        match self {
+            Direction4::North => Direction4::West,
+            Direction4::East => Direction4::North,
+            Direction4::South => Direction4::East,
+            Direction4::West => Direction4::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction4 {
+        match self {
+            Direction4::North => Direction4::East,
+            Direction4::East => Direction4::South,
+            Direction4::South => Direction4::West,
+            Direction4::West => Direction4::North,
+        }
+    }
+
+    pub fn opposite(self) -> Direction4 {
+        self.turn_right().turn_right()
+    }
+
+    /// One step in this direction as `(x, y)`, with y increasing downwards (south).
+    pub fn to_delta<T: From<i8>>(self) -> (T, T) {
+        let (x, y) = match self {
+            Direction4::North => (0, -1),
+            Direction4::East => (1, 0),
+            Direction4::South => (0, 1),
+            Direction4::West => (-1, 0),
+        };
+        (T::from(x), T::from(y))
+    }
+}
+
+/// Clockwise from 0 = North, wrapping around every 4.
+impl From<u8> for Direction4 {
+    fn from(value: u8) -> Direction4 {
+        match value % 4 {
+            0 => Direction4::North,
+            1 => Direction4::East,
+            2 => Direction4::South,
+            _ => Direction4::West,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIRECTIONS: [Direction4; 4] = [
+        Direction4::North,
+        Direction4::East,
+        Direction4::South,
+        Direction4::West,
+    ];
+
+    #[test]
+    fn rotates_round_trip() {
+        for direction in DIRECTIONS.iter().copied() {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_eq!(direction.turn_left().turn_left(), direction.opposite());
+            assert_ne!(direction.opposite(), direction);
+
+            let mut turned = direction;
+            for _ in 0..4 {
+                turned = turned.turn_right();
+            }
+            assert_eq!(turned, direction);
+        }
+    }
+
+    #[test]
+    fn converts_from_u8() {
+        for (index, direction) in DIRECTIONS.iter().enumerate() {
+            assert_eq!(Direction4::from(index as u8), *direction);
+            assert_eq!(
+                Direction4::from(index as u8).turn_right(),
+                Direction4::from(index as u8 + 1)
+            );
+        }
+    }
+
+    #[test]
+    fn converts_to_delta() {
+        assert_eq!(Direction4::North.to_delta::<i64>(), (0, -1));
+        assert_eq!(Direction4::East.to_delta::<i32>(), (1, 0));
+        for direction in DIRECTIONS.iter() {
+            let (x, y): (i64, i64) = direction.to_delta();
+            let (opposite_x, opposite_y): (i64, i64) = direction.opposite().to_delta();
+            assert_eq!((x + opposite_x, y + opposite_y), (0, 0));
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::num::ParseIntError;
+use std::result;
+
+pub type Result<T> = result::Result<T, AocError>;
+
+#[derive(Debug)]
+pub enum AocError {
+    Io(io::Error),
+    Parse(String),
+    InvalidInput(String),
+    /// The `intcode` crate reports its errors as boxed strings, so only the message is kept.
+    IntcodeError(String),
+    NoSolutionFound,
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io(err) => write!(f, "{}", err),
+            AocError::Parse(message) => write!(f, "{}", message),
+            AocError::InvalidInput(message) => write!(f, "{}", message),
+            AocError::IntcodeError(message) => write!(f, "Intcode error: {}", message),
+            AocError::NoSolutionFound => write!(f, "No solution found"),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(err: io::Error) -> AocError {
+        AocError::Io(err)
+    }
+}
+
+impl From<ParseIntError> for AocError {
+    fn from(err: ParseIntError) -> AocError {
+        AocError::Parse(err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_from_std_errors() {
+        let err = AocError::from(io::Error::new(io::ErrorKind::NotFound, "missing"));
+        assert!(matches!(err, AocError::Io(_)));
+        assert!(err.source().is_some());
+
+        let err = AocError::from("x".parse::<u32>().unwrap_err());
+        assert!(matches!(err, AocError::Parse(_)));
+        assert_eq!(err.to_string(), "invalid digit found in string");
+    }
+
+    #[test]
+    fn boxes_into_dyn_error() {
+        let boxed: Box<dyn Error> = From::from(AocError::NoSolutionFound);
+        assert_eq!(boxed.to_string(), "No solution found");
+        assert!(matches!(
+            boxed.downcast_ref::<AocError>(),
+            Some(AocError::NoSolutionFound)
+        ));
+    }
+}
@@ -0,0 +1,108 @@
+use num::Signed;
+
+use crate::Vec2;
+
+pub fn manhattan_distance<T: Copy + Ord + Signed>(a: (T, T), b: (T, T)) -> T {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+pub fn euclidean_distance_squared(a: (i64, i64), b: (i64, i64)) -> i64 {
+    (a.0 - b.0).pow(2) + (a.1 - b.1).pow(2)
+}
+
+/// -1, 0 or 1 depending on whether `x` is negative, zero or positive.
+pub fn sign(x: i64) -> i64 {
+    x.signum()
+}
+
+/// Twice the signed area of the polygon with `vertices` in order, by the Shoelace formula. Positive
+/// when the vertices go counterclockwise with y pointing up. Fewer than 3 vertices have no area.
+pub fn polygon_area_doubled(vertices: &[Vec2<i64>]) -> i64 {
+    if vertices.len() < 3 {
+        return 0;
+    }
+    (0..vertices.len())
+        .map(|i| {
+            let previous = vertices[(i + vertices.len() - 1) % vertices.len()];
+            let next = vertices[(i + 1) % vertices.len()];
+            vertices[i].x * (next.y - previous.y)
+        })
+        .sum()
+}
+
+/// Number of lattice points strictly inside a lattice polygon with `boundary_points` lattice
+/// points on its edges, by Pick's theorem. Either orientation of `doubled_area` works, and a
+/// polygon without area has no interior.
+pub fn polygon_interior_points(boundary_points: usize, doubled_area: i64) -> i64 {
+    if doubled_area == 0 {
+        return 0;
+    }
+    ((doubled_area.abs() - boundary_points as i64 + 2) / 2).max(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calculates_manhattan_distance() {
+        assert_eq!(manhattan_distance((0, 0), (3, 4)), 7);
+        assert_eq!(manhattan_distance((-2, 5), (3, -4)), 14);
+        assert_eq!(manhattan_distance((3, -4), (-2, 5)), 14);
+        assert_eq!(manhattan_distance((-7i64, -7), (-7, -7)), 0);
+    }
+
+    #[test]
+    fn calculates_euclidean_distance_squared() {
+        assert_eq!(euclidean_distance_squared((0, 0), (3, 4)), 25);
+        assert_eq!(euclidean_distance_squared((-1, -1), (2, 3)), 25);
+        assert_eq!(euclidean_distance_squared((-5, 2), (-5, 2)), 0);
+    }
+
+    #[test]
+    fn calculates_polygon_area() {
+        let unit_square = [
+            Vec2::new(0, 0),
+            Vec2::new(1, 0),
+            Vec2::new(1, 1),
+            Vec2::new(0, 1),
+        ];
+        assert_eq!(polygon_area_doubled(&unit_square), 2);
+        assert_eq!(polygon_interior_points(4, 2), 0);
+
+        let mut rectangle = [
+            Vec2::new(0, 0),
+            Vec2::new(3, 0),
+            Vec2::new(3, 4),
+            Vec2::new(0, 4),
+        ];
+        assert_eq!(polygon_area_doubled(&rectangle), 24);
+        assert_eq!(polygon_interior_points(14, 24), 6);
+        rectangle.reverse();
+        assert_eq!(polygon_area_doubled(&rectangle), -24);
+        assert_eq!(polygon_interior_points(14, -24), 6);
+
+        let triangle = [Vec2::new(0, 0), Vec2::new(4, 0), Vec2::new(0, 4)];
+        assert_eq!(polygon_area_doubled(&triangle), 16);
+        assert_eq!(polygon_interior_points(12, 16), 3);
+    }
+
+    #[test]
+    fn handles_degenerate_polygons() {
+        assert_eq!(polygon_area_doubled(&[]), 0);
+        assert_eq!(polygon_area_doubled(&[Vec2::new(3, 4)]), 0);
+        assert_eq!(polygon_area_doubled(&[Vec2::new(0, 0), Vec2::new(5, 5)]), 0);
+        assert_eq!(polygon_interior_points(0, 0), 0);
+        assert_eq!(polygon_interior_points(1, 0), 0);
+        assert_eq!(polygon_interior_points(6, 0), 0);
+    }
+
+    #[test]
+    fn calculates_sign() {
+        assert_eq!(sign(42), 1);
+        assert_eq!(sign(-42), -1);
+        assert_eq!(sign(0), 0);
+        assert_eq!(sign(i64::MIN), -1);
+        assert_eq!(sign(i64::MAX), 1);
+    }
+}
@@ -0,0 +1,325 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::bfs;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Grid<T> {
+    data: Vec<Vec<T>>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            data: vec![vec![fill; width]; height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.data.get(y).and_then(|row| row.get(x))
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.data.get_mut(y).and_then(|row| row.get_mut(x))
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, val: T) {
+        match self.get_mut(x, y) {
+            Some(cell) => *cell = val,
+            None => panic!(
+                "({}, {}) is outside of the {}x{} grid",
+                x, y, self.width, self.height
+            ),
+        }
+    }
+
+    /// In-bounds orthogonal neighbors in up, right, down, left order.
+    pub fn neighbors4(&self, x: usize, y: usize) -> Vec<(usize, usize, &T)> {
+        let mut neighbors = vec![];
+        if y > 0 {
+            neighbors.push((x, y - 1));
+        }
+        neighbors.push((x + 1, y));
+        neighbors.push((x, y + 1));
+        if x > 0 {
+            neighbors.push((x - 1, y));
+        }
+
+        neighbors
+            .into_iter()
+            .filter_map(|(x, y)| self.get(x, y).map(|cell| (x, y, cell)))
+            .collect()
+    }
+
+    /// Every cell in row-major order.
+    pub fn iter_cells(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.data
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, cell)| (x, y, cell)))
+    }
+
+    /// Fewest-steps path from `start` to `goal`, including both, moving orthogonally onto cells
+    /// for which `passable` is true.
+    pub fn shortest_path<F>(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+        passable: F,
+    ) -> Option<Vec<(usize, usize)>>
+    where
+        F: Fn(&T) -> bool,
+    {
+        bfs(
+            start,
+            |position| *position == goal,
+            |(x, y)| {
+                self.neighbors4(*x, *y)
+                    .into_iter()
+                    .filter(|(_, _, cell)| passable(cell))
+                    .map(|(x, y, _)| (x, y))
+                    .collect()
+            },
+        )
+    }
+
+    /// Number of steps in the `shortest_path` from `start` to `goal`.
+    pub fn shortest_path_distance<F>(
+        &self,
+        start: (usize, usize),
+        goal: (usize, usize),
+        passable: F,
+    ) -> Option<usize>
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.shortest_path(start, goal, passable)
+            .map(|path| path.len() - 1)
+    }
+
+    /// Steps from `(x, y)` to every cell reachable from it through `passable` cells.
+    fn flood_distances(
+        &self,
+        x: usize,
+        y: usize,
+        passable: impl Fn(&T) -> bool,
+    ) -> HashMap<(usize, usize), usize> {
+        let mut distances = HashMap::new();
+        distances.insert((x, y), 0);
+        let mut queue = VecDeque::new();
+        queue.push_back((x, y));
+        while let Some((x, y)) = queue.pop_front() {
+            let distance = distances[&(x, y)];
+            for (x, y, cell) in self.neighbors4(x, y) {
+                if passable(cell) && !distances.contains_key(&(x, y)) {
+                    distances.insert((x, y), distance + 1);
+                    queue.push_back((x, y));
+                }
+            }
+        }
+        distances
+    }
+
+    /// Rounds of spreading one cell orthogonally per round until every `passable` cell reachable
+    /// from `(x, y)` has been reached.
+    pub fn flood_fill_time(&self, x: usize, y: usize, passable: impl Fn(&T) -> bool) -> usize {
+        self.flood_distances(x, y, passable)
+            .values()
+            .max()
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Sets `(x, y)` and every cell reachable from it through `passable` cells to `value`.
+    pub fn flood_fill(&mut self, x: usize, y: usize, value: T, passable: impl Fn(&T) -> bool) {
+        for (x, y) in self.flood_distances(x, y, passable).into_keys() {
+            self.set(x, y, value.clone());
+        }
+    }
+}
+
+/// Parses newline-separated lines of characters (either Unix or Windows line endings) into a
+/// grid, ignoring trailing whitespace. Lines shorter than the longest one are padded with spaces,
+/// so every row is `width` cells long.
+pub fn parse_grid_chars(s: &str) -> Grid<char> {
+    parse_grid_mapped(s, |c| c)
+}
+
+pub fn parse_grid_mapped<T>(s: &str, f: impl Fn(char) -> T) -> Grid<T> {
+    let mut data: Vec<Vec<T>> = s
+        .lines()
+        .map(|line| line.trim_end().chars().map(&f).collect())
+        .collect();
+    while data.last().is_some_and(|row| row.is_empty()) {
+        data.pop();
+    }
+    let width = data.iter().map(|row| row.len()).max().unwrap_or(0);
+    for row in &mut data {
+        row.resize_with(width, || f(' '));
+    }
+
+    Grid {
+        width,
+        height: data.len(),
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gets_and_sets_cells() {
+        let mut grid = Grid::new(3, 2, 0);
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(2, 1), Some(&0));
+        assert_eq!(grid.get(3, 1), None);
+        assert_eq!(grid.get(0, 2), None);
+
+        grid.set(2, 1, 5);
+        *grid.get_mut(0, 0).unwrap() += 1;
+        assert_eq!(grid.get(2, 1), Some(&5));
+        assert_eq!(grid.get(0, 0), Some(&1));
+        assert_eq!(grid.get_mut(3, 0), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_when_setting_outside_of_grid() {
+        Grid::new(2, 2, 'a').set(2, 0, 'b');
+    }
+
+    #[test]
+    fn finds_neighbors4() {
+        let mut grid = Grid::new(3, 3, 0);
+        for (index, (x, y)) in [(1, 0), (2, 1), (1, 2), (0, 1)].iter().enumerate() {
+            grid.set(*x, *y, index + 1);
+        }
+        assert_eq!(
+            grid.neighbors4(1, 1),
+            vec![(1, 0, &1), (2, 1, &2), (1, 2, &3), (0, 1, &4)]
+        );
+        assert_eq!(grid.neighbors4(0, 0), vec![(1, 0, &1), (0, 1, &4)]);
+        assert_eq!(grid.neighbors4(2, 2), vec![(2, 1, &2), (1, 2, &3)]);
+    }
+
+    #[test]
+    fn finds_shortest_path() {
+        let grid = parse_grid_chars(
+            ".....\n\
+             .###.\n\
+             ...#.\n\
+             ##.#.\n\
+             .....\n",
+        );
+        let open = |cell: &char| *cell == '.';
+        let path = grid.shortest_path((0, 2), (4, 2), open).unwrap();
+        assert_eq!(path.len(), 9);
+        assert_eq!(path.first(), Some(&(0, 2)));
+        assert_eq!(path.last(), Some(&(4, 2)));
+        for step in path.windows(2) {
+            let ((x1, y1), (x2, y2)) = (step[0], step[1]);
+            assert_eq!(x1.abs_diff(x2) + y1.abs_diff(y2), 1);
+            assert_eq!(grid.get(x2, y2), Some(&'.'));
+        }
+        assert_eq!(grid.shortest_path_distance((0, 2), (4, 2), open), Some(8));
+        assert_eq!(grid.shortest_path_distance((0, 0), (0, 0), open), Some(0));
+        assert_eq!(grid.shortest_path_distance((0, 4), (4, 4), open), Some(4));
+
+        // walled off
+        assert_eq!(
+            grid.shortest_path((0, 0), (4, 4), |cell| *cell == 'x'),
+            None
+        );
+        assert_eq!(grid.shortest_path_distance((0, 0), (1, 1), open), None);
+    }
+
+    #[test]
+    fn flood_fills_grid() {
+        let mut grid = parse_grid_chars(
+            "..#..\n\
+             ..#..\n\
+             ..###\n\
+             .....\n\
+             ##...\n",
+        );
+        let open = |cell: &char| *cell == '.';
+        assert_eq!(grid.flood_fill_time(0, 0, open), 8);
+        assert_eq!(grid.flood_fill_time(4, 0, open), 2);
+        assert_eq!(Grid::new(5, 5, '.').flood_fill_time(0, 0, open), 8);
+        assert_eq!(Grid::new(5, 5, '.').flood_fill_time(2, 2, open), 4);
+
+        grid.flood_fill(0, 0, 'O', open);
+        assert_eq!(
+            grid,
+            parse_grid_chars(
+                "OO#..\n\
+                 OO#..\n\
+                 OO###\n\
+                 OOOOO\n\
+                 ##OOO\n",
+            )
+        );
+        assert_eq!(grid.flood_fill_time(0, 0, open), 0);
+    }
+
+    #[test]
+    fn iterates_cells_in_row_major_order() {
+        let mut grid = Grid::new(2, 2, '.');
+        grid.set(1, 0, '#');
+        assert_eq!(
+            grid.iter_cells().collect::<Vec<_>>(),
+            vec![(0, 0, &'.'), (1, 0, &'#'), (0, 1, &'.'), (1, 1, &'.')]
+        );
+    }
+
+    #[test]
+    fn parses_grid_chars() {
+        let grid = parse_grid_chars(".#.\r\n#..  \r\n..#\r\n\r\n");
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid, parse_grid_chars(".#.\n#..\n..#"));
+        assert_eq!(grid.get(1, 0), Some(&'#'));
+        assert_eq!(grid.get(0, 1), Some(&'#'));
+        assert_eq!(grid.get(2, 2), Some(&'#'));
+    }
+
+    #[test]
+    fn pads_short_rows() {
+        let grid = parse_grid_chars("#\n.#  \n\n#..\n");
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid.get(2, 0), Some(&' '));
+        assert_eq!(grid.get(2, 1), Some(&' '));
+        assert_eq!(grid.get(0, 2), Some(&' '));
+        assert_eq!(grid.iter_cells().count(), 12);
+        assert_eq!(grid.neighbors4(1, 2).len(), 4);
+    }
+
+    #[test]
+    fn parses_grid_mapped() {
+        let grid = parse_grid_mapped("#.\n.#\n", |c| c == '#');
+        assert_eq!(
+            grid.iter_cells()
+                .filter(|(_, _, asteroid)| **asteroid)
+                .map(|(x, y, _)| (x, y))
+                .collect::<Vec<_>>(),
+            vec![(0, 0), (1, 1)]
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use std::ops::{Div, Mul, Rem};
+
+use num::Zero;
+
+/// Greatest common divisor by the Euclidean algorithm. Meant for non-negative values, callers
+/// with signed values should pass their absolute values.
+pub fn gcd<T: Copy + Rem<Output = T> + PartialEq + Zero>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a, b);
+    while b != T::zero() {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+    a
+}
+
+/// Least common multiple. Divides before multiplying so it only overflows when the result does.
+pub fn lcm<T>(a: T, b: T) -> T
+where
+    T: Copy + Rem<Output = T> + Div<Output = T> + Mul<Output = T> + PartialEq + Zero,
+{
+    if a == T::zero() || b == T::zero() {
+        return T::zero();
+    }
+    a / gcd(a, b) * b
+}
+
+/// `base` raised to `exponent`, modulo `modulus`, by repeated squaring. The result is always in
+/// `0..modulus`. Squares in `u128` so intermediate products can't overflow. Panics if `modulus` is
+/// 0.
+pub fn mod_pow(base: u64, exponent: u64, modulus: u64) -> u64 {
+    let modulus = u128::from(modulus);
+    let mut base = u128::from(base) % modulus;
+    let mut exponent = exponent;
+    let mut result = 1 % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    result as u64
+}
+
+/// `(gcd, x, y)` such that `a * x + b * y == gcd`, by the extended Euclidean algorithm. The gcd
+/// is never negative.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, or `None` if `a` and `m` aren't coprime or `m`
+/// isn't positive.
+pub fn mod_inv(a: i64, m: i64) -> Option<i64> {
+    if m <= 0 {
+        return None;
+    }
+    match extended_gcd(a.rem_euclid(m), m) {
+        (1, x, _) => Some(x.rem_euclid(m)),
+        _ => None,
+    }
+}
+
+/// The smallest non-negative `x` with `x ≡ remainder (mod modulus)` for every
+/// `(remainder, modulus)` pair, found by successive substitution. `None` if the moduli aren't
+/// pairwise coprime, any modulus isn't positive or their product overflows an `i64`.
+pub fn chinese_remainder_theorem(congruences: &[(i64, i64)]) -> Option<i64> {
+    let mut x: i64 = 0;
+    let mut product: i64 = 1;
+    for &(remainder, modulus) in congruences {
+        if modulus <= 0 {
+            return None;
+        }
+        // pick x + product * t that also satisfies this congruence
+        // both reduced first so the difference can't overflow
+        let t = mul_mod(
+            remainder.rem_euclid(modulus) - x.rem_euclid(modulus),
+            mod_inv(product.rem_euclid(modulus), modulus)?,
+            modulus,
+        );
+        let next_product = product.checked_mul(modulus)?;
+        // t < modulus, so this stays below next_product
+        x += product * t;
+        product = next_product;
+    }
+    Some(x)
+}
+
+/// `a * b` modulo `modulus`, in `0..modulus`. Multiplies in `i128` so the product can't overflow.
+/// Panics if `modulus` is 0.
+pub fn mul_mod(a: i64, b: i64, modulus: i64) -> i64 {
+    (i128::from(a) * i128::from(b)).rem_euclid(i128::from(modulus)) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calculates_gcd() {
+        assert_eq!(gcd(12, 8), 4);
+        assert_eq!(gcd(8, 12), 4);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(5, 0), 5);
+        assert_eq!(gcd(17u64, 5), 1);
+    }
+
+    #[test]
+    fn calculates_lcm() {
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(lcm(7u64, 1), 7);
+        assert_eq!(lcm(u64::MAX, u64::MAX), u64::MAX);
+        assert_eq!(lcm(1u64 << 62, 1 << 40), 1 << 62);
+    }
+
+    #[test]
+    fn calculates_mod_pow() {
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(3, 0, 7), 1);
+        assert_eq!(mod_pow(0, 0, 7), 1);
+        assert_eq!(mod_pow(5, 3, 1), 0);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+        // Fermat's little theorem, with intermediate products that overflow u64
+        let prime = 119_315_717_514_047;
+        assert_eq!(mod_pow(2020, prime - 1, prime), 1);
+        assert_eq!(mod_pow(u64::MAX, 2, u64::MAX - 1), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_on_mod_pow_by_zero() {
+        mod_pow(2, 3, 0);
+    }
+
+    #[test]
+    fn calculates_mul_mod() {
+        assert_eq!(mul_mod(7, 8, 10), 6);
+        assert_eq!(mul_mod(-7, 8, 10), 4);
+        let prime = 119_315_717_514_047;
+        assert_eq!(mul_mod(prime - 1, prime - 1, prime), 1);
+    }
+
+    #[test]
+    fn calculates_extended_gcd() {
+        assert_eq!(extended_gcd(35, 15), (5, 1, -2));
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+        assert_eq!(extended_gcd(7, 0), (7, 1, 0));
+        assert_eq!(extended_gcd(0, 7), (7, 0, 1));
+        for &(a, b) in [(-35, 15), (12, -18), (17, 5), (1 << 40, 3 << 20)].iter() {
+            let (gcd, x, y) = extended_gcd(a, b);
+            assert!(gcd > 0);
+            assert_eq!(a * x + b * y, gcd);
+        }
+    }
+
+    #[test]
+    fn calculates_mod_inv() {
+        assert_eq!(mod_inv(3, 11), Some(4));
+        assert_eq!(mod_inv(6, 9), None);
+        assert_eq!(mod_inv(3, 7), Some(5));
+        assert_eq!(mod_inv(-3, 7), Some(2));
+        assert_eq!(mod_inv(10, 17), Some(12));
+        assert_eq!(mod_inv(4, 10), None);
+        assert_eq!(mod_inv(0, 7), None);
+        assert_eq!(mod_inv(3, 0), None);
+        assert_eq!(mod_inv(3, -11), None);
+        let prime = 119_315_717_514_047;
+        assert_eq!(
+            mod_inv(2020, prime),
+            Some(mod_pow(2020, prime as u64 - 2, prime as u64) as i64)
+        );
+    }
+
+    #[test]
+    fn solves_chinese_remainder_theorem() {
+        assert_eq!(
+            chinese_remainder_theorem(&[(2, 3), (3, 5), (2, 7)]),
+            Some(23)
+        );
+        assert_eq!(
+            chinese_remainder_theorem(&[(0, 3), (3, 4), (4, 5)]),
+            Some(39)
+        );
+        assert_eq!(chinese_remainder_theorem(&[(-1, 3), (7, 5)]), Some(2));
+        assert_eq!(
+            chinese_remainder_theorem(&[(0, 2), (i64::MIN, 3)]),
+            Some(i64::MIN.rem_euclid(6))
+        );
+        assert_eq!(
+            chinese_remainder_theorem(&[(i64::MAX, 5), (i64::MIN + 1, 7)]),
+            Some(7)
+        );
+        assert_eq!(chinese_remainder_theorem(&[(5, 7)]), Some(5));
+        assert_eq!(chinese_remainder_theorem(&[]), Some(0));
+        // not pairwise coprime, whether or not the congruences agree
+        assert_eq!(chinese_remainder_theorem(&[(1, 2), (0, 4)]), None);
+        assert_eq!(chinese_remainder_theorem(&[(1, 6), (1, 4)]), None);
+        assert_eq!(chinese_remainder_theorem(&[(2, 3), (0, 0)]), None);
+        assert_eq!(chinese_remainder_theorem(&[(2, -3)]), None);
+        let x = 1_000_000_000_000_000;
+        let moduli = [999_999_937, 999_999_929, 999_999_893];
+        assert_eq!(
+            chinese_remainder_theorem(&[(x % moduli[0], moduli[0]), (x % moduli[1], moduli[1])]),
+            Some(x)
+        );
+        assert_eq!(
+            chinese_remainder_theorem(&moduli.map(|modulus| (1, modulus))),
+            None
+        );
+    }
+}
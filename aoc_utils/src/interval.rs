@@ -0,0 +1,78 @@
+// intervals are inclusive `(start, end)` pairs with `start <= end`
+
+/// The interval covered by both `a` and `b`, or `None` if they share no integers.
+pub fn interval_overlap(a: (i64, i64), b: (i64, i64)) -> Option<(i64, i64)> {
+    let start = a.0.max(b.0);
+    let end = a.1.min(b.1);
+    if start <= end {
+        Some((start, end))
+    } else {
+        None
+    }
+}
+
+/// The single interval covering `a` and `b`, or `None` if there is a gap between them.
+/// Adjacent intervals like `(1, 3)` and `(4, 6)` have no gap.
+pub fn interval_union(a: (i64, i64), b: (i64, i64)) -> Option<(i64, i64)> {
+    let (first, second) = if a.0 <= b.0 { (a, b) } else { (b, a) };
+    if second.0 - first.1 <= 1 {
+        Some((first.0, first.1.max(second.1)))
+    } else {
+        None
+    }
+}
+
+/// Combines overlapping and adjacent intervals, returning the rest sorted by start.
+pub fn merge_intervals(mut intervals: Vec<(i64, i64)>) -> Vec<(i64, i64)> {
+    intervals.sort_unstable();
+    let mut merged: Vec<(i64, i64)> = vec![];
+    for interval in intervals {
+        match merged
+            .last_mut()
+            .and_then(|last| interval_union(*last, interval).map(|union| (last, union)))
+        {
+            Some((last, union)) => *last = union,
+            None => merged.push(interval),
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlaps_intervals() {
+        assert_eq!(interval_overlap((1, 5), (3, 8)), Some((3, 5)));
+        assert_eq!(interval_overlap((3, 8), (1, 5)), Some((3, 5)));
+        assert_eq!(interval_overlap((1, 10), (4, 6)), Some((4, 6)));
+        assert_eq!(interval_overlap((1, 3), (3, 5)), Some((3, 3)));
+        assert_eq!(interval_overlap((1, 3), (4, 6)), None);
+        assert_eq!(interval_overlap((-5, -1), (2, 6)), None);
+    }
+
+    #[test]
+    fn unites_intervals() {
+        assert_eq!(interval_union((1, 5), (3, 8)), Some((1, 8)));
+        assert_eq!(interval_union((3, 8), (1, 5)), Some((1, 8)));
+        assert_eq!(interval_union((1, 10), (4, 6)), Some((1, 10)));
+        assert_eq!(interval_union((1, 3), (4, 6)), Some((1, 6)));
+        assert_eq!(interval_union((4, 6), (1, 3)), Some((1, 6)));
+        assert_eq!(interval_union((1, 3), (5, 6)), None);
+        assert_eq!(interval_union((-5, -1), (2, 6)), None);
+    }
+
+    #[test]
+    fn merges_intervals() {
+        assert_eq!(
+            merge_intervals(vec![(10, 12), (1, 3), (2, 5), (6, 7), (14, 20), (15, 16)]),
+            vec![(1, 7), (10, 12), (14, 20)]
+        );
+        assert_eq!(
+            merge_intervals(vec![(5, 5), (-3, 0)]),
+            vec![(-3, 0), (5, 5)]
+        );
+        assert_eq!(merge_intervals(vec![]), vec![]);
+    }
+}
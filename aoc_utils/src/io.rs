@@ -0,0 +1,94 @@
+use std::fs::read_to_string;
+use std::io;
+
+pub fn read_string(filename: &str) -> io::Result<String> {
+    read_to_string(filename)
+}
+
+pub fn read_lines(filename: &str) -> io::Result<Vec<String>> {
+    Ok(read_string(filename)?.lines().map(String::from).collect())
+}
+
+/// Like `read_lines`, but with each line trimmed and blank lines left out.
+pub fn read_lines_trimmed(filename: &str) -> io::Result<Vec<String>> {
+    Ok(read_string(filename)?
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+/// The only line of a file, trimmed. Errors if the file is blank or has more than one line.
+pub fn read_single_line(filename: &str) -> io::Result<String> {
+    let contents = read_string(filename)?;
+    let line = contents.trim();
+    if line.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} is empty", filename),
+        ));
+    }
+    if line.contains('\n') {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} has more than one line", filename),
+        ));
+    }
+    Ok(line.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "input/test_lines.txt";
+
+    #[test]
+    fn reads_lines() {
+        assert_eq!(
+            read_string(TEST_INPUT).unwrap(),
+            "  first line  \n\nsecond\n   \n\tthird\t\n"
+        );
+        assert_eq!(
+            read_lines(TEST_INPUT).unwrap(),
+            vec!["  first line  ", "", "second", "   ", "\tthird\t"]
+        );
+        assert_eq!(
+            read_lines_trimmed(TEST_INPUT).unwrap(),
+            vec!["first line", "second", "third"]
+        );
+    }
+
+    #[test]
+    fn reads_single_line() {
+        assert_eq!(
+            read_single_line("input/test_single_line.txt").unwrap(),
+            "245318-765747"
+        );
+        assert_eq!(
+            read_single_line("input/test_empty.txt")
+                .unwrap_err()
+                .to_string(),
+            "input/test_empty.txt is empty"
+        );
+        assert_eq!(
+            read_single_line("input/test_two_lines.txt")
+                .unwrap_err()
+                .to_string(),
+            "input/test_two_lines.txt has more than one line"
+        );
+        assert_eq!(
+            read_single_line(TEST_INPUT).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn errors_on_missing_file() {
+        assert_eq!(
+            read_lines("input/missing.txt").unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+    }
+}
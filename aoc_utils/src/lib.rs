@@ -0,0 +1,38 @@
+mod bounding_box;
+mod combinatorics;
+mod direction;
+mod error;
+mod geometry;
+mod grid;
+mod integer;
+mod interval;
+mod io;
+mod matrix;
+mod ordering;
+mod parse;
+mod point;
+mod run;
+mod search;
+mod testing;
+mod vector;
+
+pub use bounding_box::BoundingBox;
+pub use combinatorics::{combinations, permutations};
+pub use direction::Direction4;
+pub use error::{AocError, Result};
+pub use geometry::{
+    euclidean_distance_squared, manhattan_distance, polygon_area_doubled, polygon_interior_points,
+    sign,
+};
+pub use grid::{parse_grid_chars, parse_grid_mapped, Grid};
+pub use integer::{chinese_remainder_theorem, extended_gcd, gcd, lcm, mod_inv, mod_pow, mul_mod};
+pub use interval::{interval_overlap, interval_union, merge_intervals};
+pub use io::{read_lines, read_lines_trimmed, read_single_line, read_string};
+pub use matrix::Matrix2x2;
+pub use ordering::{max_by_key_stable, min_by_key_stable};
+pub use parse::{parse_all_integers, parse_numbers, parse_numbers_at, TokenError};
+pub use point::Point2D;
+pub use run::run;
+pub use search::{bfs, bfs_cost};
+pub use testing::assert_grid_eq;
+pub use vector::{Vec2, Vec3};
@@ -0,0 +1,134 @@
+use crate::mul_mod;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Matrix2x2<T> {
+    data: [[T; 2]; 2],
+}
+
+impl<T> Matrix2x2<T> {
+    pub fn new(data: [[T; 2]; 2]) -> Matrix2x2<T> {
+        Matrix2x2 { data }
+    }
+}
+
+impl Matrix2x2<i64> {
+    pub fn identity() -> Matrix2x2<i64> {
+        Matrix2x2::new([[1, 0], [0, 1]])
+    }
+
+    /// The linear function `x -> a * x + b`, as `[[a, b], [0, 1]]` so that composing two of them
+    /// is matrix multiplication.
+    pub fn linear(a: i64, b: i64) -> Matrix2x2<i64> {
+        Matrix2x2::new([[a, b], [0, 1]])
+    }
+
+    /// Matrix product modulo `modulus`, with every entry in `0..modulus`.
+    pub fn mul(&self, other: &Self, modulus: i64) -> Self {
+        let mut data = [[0; 2]; 2];
+        for (row, data_row) in data.iter_mut().enumerate() {
+            for (column, entry) in data_row.iter_mut().enumerate() {
+                // summed in i128, two products just below a huge modulus overflow an i64
+                *entry = add_mod(
+                    mul_mod(self.data[row][0], other.data[0][column], modulus),
+                    mul_mod(self.data[row][1], other.data[1][column], modulus),
+                    modulus,
+                );
+            }
+        }
+        Matrix2x2 { data }
+    }
+
+    /// This matrix raised to `n` modulo `modulus`, by repeated squaring.
+    pub fn pow(self, n: u64, modulus: i64) -> Self {
+        let mut base = self;
+        let mut exponent = n;
+        let mut result = Matrix2x2::identity();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul(&base, modulus);
+            }
+            base = base.mul(&base, modulus);
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// The top entry of this matrix times `[x, 1]`, which is `a * x + b` for a `linear` matrix.
+    pub fn apply(&self, x: i64, modulus: i64) -> i64 {
+        add_mod(
+            mul_mod(self.data[0][0], x, modulus),
+            self.data[0][1],
+            modulus,
+        )
+    }
+}
+
+fn add_mod(a: i64, b: i64, modulus: i64) -> i64 {
+    (i128::from(a) + i128::from(b)).rem_euclid(i128::from(modulus)) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mod_pow;
+
+    const PRIME: i64 = 1_000_000_007;
+
+    #[test]
+    fn multiplies_matrices() {
+        let a = Matrix2x2::new([[1, 2], [3, 4]]);
+        let b = Matrix2x2::new([[5, 6], [7, 8]]);
+        assert_eq!(a.mul(&b, 100), Matrix2x2::new([[19, 22], [43, 50]]));
+        assert_eq!(a.mul(&b, 10), Matrix2x2::new([[9, 2], [3, 0]]));
+        assert_eq!(a.mul(&Matrix2x2::identity(), PRIME), a);
+        assert_eq!(a.pow(0, PRIME), Matrix2x2::identity());
+        assert_eq!(a.pow(3, PRIME), a.mul(&a, PRIME).mul(&a, PRIME));
+    }
+
+    #[test]
+    fn raises_identity_to_powers() {
+        assert_eq!(
+            Matrix2x2::identity().pow(1000, PRIME),
+            Matrix2x2::identity()
+        );
+    }
+
+    #[test]
+    fn applies_linear_recurrences() {
+        let n = 1_000_000_000_000_000_000;
+        // x -> x + 1
+        assert_eq!(
+            Matrix2x2::linear(1, 1).pow(n, PRIME).apply(0, PRIME),
+            (n % PRIME as u64) as i64
+        );
+        // x -> 3x
+        assert_eq!(
+            Matrix2x2::linear(3, 0).pow(n, PRIME).apply(1, PRIME),
+            mod_pow(3, n, PRIME as u64) as i64
+        );
+        // x -> 3x + 2, i.e. x + 1 triples each step
+        assert_eq!(
+            Matrix2x2::linear(3, 2).pow(n, PRIME).apply(4, PRIME),
+            (5 * mod_pow(3, n, PRIME as u64) as i64 - 1).rem_euclid(PRIME)
+        );
+        assert_eq!(Matrix2x2::linear(-1, -1).apply(3, 10), 6);
+    }
+
+    #[test]
+    fn handles_moduli_near_i64_max() {
+        let modulus = i64::MAX;
+        let almost = Matrix2x2::new([[modulus - 1; 2]; 2]);
+        // every entry is 2 * (-1)^2
+        assert_eq!(almost.mul(&almost, modulus), Matrix2x2::new([[2; 2]; 2]));
+        assert_eq!(almost.pow(2, modulus), Matrix2x2::new([[2; 2]; 2]));
+        assert_eq!(
+            Matrix2x2::linear(modulus - 1, modulus - 1).apply(modulus - 1, modulus),
+            0
+        );
+        // an unreduced entry from `linear`
+        assert_eq!(
+            Matrix2x2::linear(1, i64::MAX).apply(i64::MAX - 2, i64::MAX - 1),
+            0
+        );
+    }
+}
@@ -0,0 +1,40 @@
+/// The item with the smallest `key`, breaking ties by picking the smallest item so the result
+/// doesn't depend on iteration order (e.g. of a `HashMap`).
+pub fn min_by_key_stable<T: Ord, K: Ord>(
+    items: impl IntoIterator<Item = T>,
+    key: impl Fn(&T) -> K,
+) -> Option<T> {
+    items
+        .into_iter()
+        .min_by(|a, b| key(a).cmp(&key(b)).then_with(|| a.cmp(b)))
+}
+
+/// The item with the largest `key`, breaking ties by picking the smallest item so the result
+/// doesn't depend on iteration order (e.g. of a `HashMap`).
+pub fn max_by_key_stable<T: Ord, K: Ord>(
+    items: impl IntoIterator<Item = T>,
+    key: impl Fn(&T) -> K,
+) -> Option<T> {
+    items
+        .into_iter()
+        .max_by(|a, b| key(a).cmp(&key(b)).then_with(|| b.cmp(a)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breaks_ties_by_item() {
+        let words = ["pear", "fig", "kiwi", "date", "plum"];
+        assert_eq!(min_by_key_stable(words, |word| word.len()), Some("fig"));
+        assert_eq!(max_by_key_stable(words, |word| word.len()), Some("date"));
+        let reversed = words.iter().rev().copied();
+        assert_eq!(max_by_key_stable(reversed, |word| word.len()), Some("date"));
+        assert_eq!(
+            min_by_key_stable(["kiwi", "plum", "date"], |word| word.len()),
+            Some("date")
+        );
+        assert_eq!(min_by_key_stable(Vec::<u8>::new(), |n| *n), None);
+    }
+}
@@ -0,0 +1,203 @@
+use std::fmt::Display;
+use std::result;
+use std::str::FromStr;
+
+use crate::{AocError, Result};
+
+/// A token of a number list that couldn't be parsed, with where it sits in the input.
+#[derive(Debug, PartialEq)]
+pub struct TokenError {
+    /// Byte offset of the token in the input.
+    pub start: usize,
+    /// Length of the token in bytes.
+    pub len: usize,
+    pub message: String,
+}
+
+/// Parses a `sep`-separated list of numbers, ignoring surrounding whitespace and empty tokens.
+pub fn parse_numbers<T>(s: &str, sep: char) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    parse_tokens(s, sep, true).map_err(|err| AocError::Parse(err.message))
+}
+
+/// Like `parse_numbers`, but for lists where position matters: an empty token, as in `1,,2`, is
+/// an error rather than skipped. Only blank input, or trailing whitespace, is allowed. The error
+/// says where the bad token is, e.g. so callers can point at it in the source.
+pub fn parse_numbers_at<T>(s: &str, sep: char) -> result::Result<Vec<T>, TokenError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    if s.trim().is_empty() {
+        return Ok(vec![]);
+    }
+    parse_tokens(s, sep, false)
+}
+
+fn parse_tokens<T>(s: &str, sep: char, skip_empty: bool) -> result::Result<Vec<T>, TokenError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut numbers = vec![];
+    let mut offset = 0;
+    for token in s.split(sep) {
+        let end = offset + token.len();
+        let trimmed = token.trim();
+        if !trimmed.is_empty() {
+            let start = offset + token.len() - token.trim_start().len();
+            numbers.push(trimmed.parse().map_err(|err| TokenError {
+                start,
+                len: trimmed.len(),
+                message: format!("Invalid number {:?}: {}", trimmed, err),
+            })?);
+        } else if !(skip_empty || end == s.len() && sep.is_whitespace()) {
+            // point at the separator after the missing number, or before it at the very end
+            let start = if end < s.len() {
+                end
+            } else {
+                offset - sep.len_utf8()
+            };
+            return Err(TokenError {
+                start,
+                len: sep.len_utf8(),
+                message: "Missing number".to_string(),
+            });
+        }
+        offset = end + sep.len_utf8();
+    }
+    Ok(numbers)
+}
+
+/// Every integer embedded in `s`, such as the coordinates in `<x=-1, y=0, z=2>`. A `-` directly
+/// before a run of digits makes it negative. Errors if a run doesn't parse as a `T`, e.g. because
+/// it overflows it.
+pub fn parse_all_integers<T>(s: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let bytes = s.as_bytes();
+    let mut integers = vec![];
+    let mut index = 0;
+    while index < bytes.len() {
+        let start = index;
+        if bytes[index] == b'-' {
+            index += 1;
+        }
+        let digits_start = index;
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        if index == digits_start {
+            index = start + 1;
+            continue;
+        }
+        let token = &s[start..index];
+        integers.push(
+            token
+                .parse()
+                .map_err(|err| AocError::Parse(format!("Invalid integer {:?}: {}", token, err)))?,
+        );
+    }
+    Ok(integers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_comma_separated_numbers() {
+        assert_eq!(
+            parse_numbers::<i64>("1,-2,30,,4\n", ',').unwrap(),
+            vec![1, -2, 30, 4]
+        );
+        assert_eq!(parse_numbers::<i64>("", ',').unwrap(), vec![]);
+    }
+
+    #[test]
+    fn parses_newline_separated_numbers() {
+        assert_eq!(
+            parse_numbers::<u64>("12\n14\n\n1969\r\n100756\n", '\n').unwrap(),
+            vec![12, 14, 1969, 100756]
+        );
+    }
+
+    #[test]
+    fn reports_malformed_token_position() {
+        assert_eq!(
+            parse_numbers_at::<i64>("1, 0,  1o1 ,0", ','),
+            Err(TokenError {
+                start: 7,
+                len: 3,
+                message: "Invalid number \"1o1\": invalid digit found in string".to_string()
+            })
+        );
+        assert_eq!(parse_numbers_at::<i64>(" 1 ,2\n", ',').unwrap(), vec![1, 2]);
+        assert_eq!(parse_numbers_at::<i64>("1\n2\n", '\n').unwrap(), vec![1, 2]);
+        assert_eq!(parse_numbers_at::<i64>(" \n", ',').unwrap(), vec![]);
+    }
+
+    #[test]
+    fn rejects_missing_numbers_in_positional_lists() {
+        let missing = |start| {
+            Err(TokenError {
+                start,
+                len: 1,
+                message: "Missing number".to_string(),
+            })
+        };
+        assert_eq!(parse_numbers_at::<i64>("1,,0,0,99", ','), missing(2));
+        assert_eq!(parse_numbers_at::<i64>("1, ,0", ','), missing(3));
+        assert_eq!(parse_numbers_at::<i64>(",1", ','), missing(0));
+        assert_eq!(parse_numbers_at::<i64>("1,2,\n", ','), missing(3));
+        assert_eq!(parse_numbers_at::<i64>("1\n\n2", '\n'), missing(2));
+    }
+
+    #[test]
+    fn parses_all_integers() {
+        assert_eq!(
+            parse_all_integers::<i64>("<x=-1, y=0, z=2>").unwrap(),
+            vec![-1, 0, 2]
+        );
+        assert_eq!(
+            parse_all_integers::<i32>("10 ORE, 7-3 --4 - 5 x-").unwrap(),
+            vec![10, 7, -3, -4, 5]
+        );
+        assert_eq!(parse_all_integers::<u8>("0 255").unwrap(), vec![0, 255]);
+        assert_eq!(
+            parse_all_integers::<i64>("no numbers here").unwrap(),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn rejects_integers_that_do_not_fit() {
+        assert_eq!(
+            parse_all_integers::<u8>("1 256 2").unwrap_err().to_string(),
+            "Invalid integer \"256\": number too large to fit in target type"
+        );
+        assert!(parse_all_integers::<u8>("-1").is_err());
+        assert!(parse_all_integers::<i64>("<x=99999999999999999999, y=1, z=2>").is_err());
+    }
+
+    #[test]
+    fn reports_malformed_token() {
+        assert_eq!(
+            parse_numbers::<i64>("1,2,x3,4", ',')
+                .unwrap_err()
+                .to_string(),
+            "Invalid number \"x3\": invalid digit found in string"
+        );
+        assert_eq!(
+            parse_numbers::<u32>("12\n-14\n", '\n')
+                .unwrap_err()
+                .to_string(),
+            "Invalid number \"-14\": invalid digit found in string"
+        );
+    }
+}
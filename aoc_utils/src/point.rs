@@ -0,0 +1,116 @@
+use std::fmt;
+use std::ops::{Add, Neg, Sub};
+
+use num::Signed;
+
+use crate::manhattan_distance;
+
+/// Ordered by `x`, then `y`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Point2D<T: Copy + Ord> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Copy + Ord> Point2D<T> {
+    pub fn new(x: T, y: T) -> Point2D<T> {
+        Point2D { x, y }
+    }
+}
+
+impl<T: Copy + Ord + Signed> Point2D<T> {
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        manhattan_distance((self.x, self.y), (other.x, other.y))
+    }
+
+    pub fn manhattan_distance_from_origin(&self) -> T {
+        self.manhattan_distance(&Point2D::new(T::zero(), T::zero()))
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T>> Add for Point2D<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point2D {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T>> Sub for Point2D<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point2D {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl<T: Copy + Ord + Neg<Output = T>> Neg for Point2D<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point2D {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T: Copy + Ord> From<(T, T)> for Point2D<T> {
+    fn from((x, y): (T, T)) -> Point2D<T> {
+        Point2D { x, y }
+    }
+}
+
+impl<T: Copy + Ord + fmt::Display> fmt::Display for Point2D<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn does_point_arithmetic() {
+        let a = Point2D::new(3, -4);
+        let b = Point2D::from((-1, 2));
+        assert_eq!(a + b, Point2D { x: 2, y: -2 });
+        assert_eq!(a - b, Point2D { x: 4, y: -6 });
+        assert_eq!(-a, Point2D { x: -3, y: 4 });
+        assert_eq!(
+            Point2D::new(1usize, 2) + Point2D::new(3, 4),
+            Point2D::new(4, 6)
+        );
+    }
+
+    #[test]
+    fn calculates_manhattan_distance() {
+        let a = Point2D::new(3i64, -4);
+        assert_eq!(a.manhattan_distance(&Point2D::new(0, 0)), 7);
+        assert_eq!(a.manhattan_distance(&Point2D::new(-1, 2)), 10);
+        assert_eq!(a.manhattan_distance(&a), 0);
+        assert_eq!(
+            Point2D::new(-2i32, -5).manhattan_distance(&Point2D::new(4, -1)),
+            10
+        );
+    }
+
+    #[test]
+    fn calculates_manhattan_distance_from_origin() {
+        assert_eq!(Point2D::new(3i32, 3).manhattan_distance_from_origin(), 6);
+        assert_eq!(Point2D::new(-6i32, 5).manhattan_distance_from_origin(), 11);
+        assert_eq!(Point2D::new(0i64, 0).manhattan_distance_from_origin(), 0);
+    }
+
+    #[test]
+    fn displays_points() {
+        assert_eq!(Point2D::new(-1, 2).to_string(), "(-1, 2)");
+    }
+}
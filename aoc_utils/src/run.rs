@@ -0,0 +1,76 @@
+use std::error::Error;
+use std::process::ExitCode;
+use std::result;
+
+/// Runs a day's `main`, printing any error along with its sources (rather than the `Debug` form
+/// returning it from `main` would print) and exiting with a nonzero code.
+pub fn run<E: Into<Box<dyn Error>>>(f: impl FnOnce() -> result::Result<(), E>) -> ExitCode {
+    match f() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", error_message(err.into().as_ref()));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn error_message(err: &dyn Error) -> String {
+    let mut message = format!("Error: {}", err);
+    let mut source = err.source();
+    while let Some(cause) = source {
+        message += &format!("\n  caused by: {}", cause);
+        source = cause.source();
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt;
+
+    use crate::AocError;
+
+    #[derive(Debug)]
+    struct ReadFailed(AocError);
+
+    impl fmt::Display for ReadFailed {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "failed to read the puzzle input")
+        }
+    }
+
+    impl Error for ReadFailed {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn maps_errors_to_exit_codes() {
+        assert_eq!(
+            run(|| -> result::Result<(), AocError> { Ok(()) }),
+            ExitCode::SUCCESS
+        );
+        assert_eq!(
+            run(|| -> result::Result<(), AocError> { Err(AocError::NoSolutionFound) }),
+            ExitCode::FAILURE
+        );
+        assert_eq!(
+            run(|| -> result::Result<(), Box<dyn Error>> { Err(From::from("boxed")) }),
+            ExitCode::FAILURE
+        );
+    }
+
+    #[test]
+    fn prints_error_chain() {
+        assert_eq!(
+            error_message(&AocError::NoSolutionFound),
+            "Error: No solution found"
+        );
+        assert_eq!(
+            error_message(&ReadFailed(AocError::Parse("bad mass".to_string()))),
+            "Error: failed to read the puzzle input\n  caused by: bad mass"
+        );
+    }
+}
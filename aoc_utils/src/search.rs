@@ -0,0 +1,107 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use num::Zero;
+
+/// Breadth-first search from `start`, returning the shortest path (including `start` and the goal)
+/// to the first state satisfying `is_goal`, or `None` if no reachable state does.
+pub fn bfs<S, F, G>(start: S, is_goal: G, neighbors: F) -> Option<Vec<S>>
+where
+    S: Hash + Eq + Clone,
+    F: Fn(&S) -> Vec<S>,
+    G: Fn(&S) -> bool,
+{
+    let mut parents: HashMap<S, Option<S>> = HashMap::new();
+    parents.insert(start.clone(), None);
+    let mut queue = VecDeque::new();
+    queue.push_back(start);
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            let mut path = vec![state.clone()];
+            let mut current = state;
+            while let Some(Some(parent)) = parents.get(&current).cloned() {
+                path.push(parent.clone());
+                current = parent;
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for neighbor in neighbors(&state) {
+            if !parents.contains_key(&neighbor) {
+                parents.insert(neighbor.clone(), Some(state.clone()));
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    None
+}
+
+/// Dijkstra's algorithm from `start`, returning the lowest total cost to reach a state satisfying
+/// `is_goal`. Costs must not be negative.
+pub fn bfs_cost<S, F, G, C>(start: S, is_goal: G, neighbors_with_cost: F) -> Option<C>
+where
+    S: Hash + Eq + Clone,
+    F: Fn(&S) -> Vec<(S, C)>,
+    G: Fn(&S) -> bool,
+    C: Copy + Ord + Add<Output = C> + Zero,
+{
+    let mut best_costs: HashMap<S, C> = HashMap::new();
+    best_costs.insert(start.clone(), C::zero());
+    // states aren't necessarily `Ord`, so the heap orders indexes into `states` instead
+    let mut states = vec![start];
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((C::zero(), 0)));
+
+    while let Some(Reverse((cost, index))) = queue.pop() {
+        let state = states[index].clone();
+        if best_costs.get(&state).is_some_and(|best| cost > *best) {
+            continue;
+        }
+        if is_goal(&state) {
+            return Some(cost);
+        }
+        for (neighbor, step_cost) in neighbors_with_cost(&state) {
+            let neighbor_cost = cost + step_cost;
+            if best_costs
+                .get(&neighbor)
+                .is_none_or(|best| neighbor_cost < *best)
+            {
+                best_costs.insert(neighbor.clone(), neighbor_cost);
+                states.push(neighbor);
+                queue.push(Reverse((neighbor_cost, states.len() - 1)));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_shortest_path() {
+        // a number line where each state can step by +1 or *2
+        let path = bfs(1, |n| *n == 10, |n| vec![n + 1, n * 2]).unwrap();
+        assert_eq!(path, vec![1, 2, 4, 5, 10]);
+        assert_eq!(bfs(3, |n| *n == 3, |n| vec![n + 1]).unwrap(), vec![3]);
+        assert_eq!(bfs(0, |n| *n == 5, |n| vec![(n + 1) % 4]), None);
+    }
+
+    #[test]
+    fn finds_lowest_cost() {
+        // the direct edge costs more than going around
+        let edges = |node: &char| match node {
+            'a' => vec![('b', 1), ('d', 10)],
+            'b' => vec![('c', 2)],
+            'c' => vec![('d', 3)],
+            _ => vec![],
+        };
+        assert_eq!(bfs_cost('a', |node| *node == 'd', edges), Some(6u32));
+        assert_eq!(bfs_cost('a', |node| *node == 'a', edges), Some(0));
+        assert_eq!(bfs_cost('b', |node| *node == 'a', edges), None);
+    }
+}
@@ -0,0 +1,52 @@
+/// Compares two multi-line ASCII-art grids, ignoring trailing whitespace on each line. On a
+/// mismatch, panics with both grids side by side and the differing lines marked.
+pub fn assert_grid_eq(actual: &str, expected: &str) {
+    let actual: Vec<&str> = actual.lines().map(|line| line.trim_end()).collect();
+    let expected: Vec<&str> = expected.lines().map(|line| line.trim_end()).collect();
+    if actual == expected {
+        return;
+    }
+
+    let width = expected.iter().map(|line| line.len()).max().unwrap_or(0);
+    let mut diff = String::new();
+    for row in 0..actual.len().max(expected.len()) {
+        let expected_line = expected.get(row).copied().unwrap_or("");
+        let actual_line = actual.get(row).copied().unwrap_or("");
+        let marker = if expected.get(row) == actual.get(row) {
+            ' '
+        } else {
+            '>'
+        };
+        diff += &format!(
+            "{} {:>3} | {:<width$} | {}\n",
+            marker,
+            row,
+            expected_line,
+            actual_line,
+            width = width
+        );
+    }
+    panic!("grids differ (expected | actual):\n{}", diff);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ignores_trailing_whitespace() {
+        assert_grid_eq("#.# \n ## \n", "#.#\n ##");
+    }
+
+    #[test]
+    #[should_panic(expected = ">   1 | .#. | .##")]
+    fn marks_mismatched_lines() {
+        assert_grid_eq("###\n.##\n", "###\n.#.\n");
+    }
+
+    #[test]
+    #[should_panic(expected = ">   2 |     | ...")]
+    fn marks_missing_lines() {
+        assert_grid_eq("###\n.#.\n...", "###\n.#.");
+    }
+}
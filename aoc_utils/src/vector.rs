@@ -0,0 +1,162 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use num::Zero;
+
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vec2<T> {
+    pub fn new(x: T, y: T) -> Vec2<T> {
+        Vec2 { x, y }
+    }
+}
+
+impl<T> Vec3<T> {
+    pub fn new(x: T, y: T, z: T) -> Vec3<T> {
+        Vec3 { x, y, z }
+    }
+}
+
+// the arithmetic is the same component-wise operation for both vectors
+macro_rules! impl_vector_ops {
+    ($vector:ident { $($component:ident),+ }) => {
+        impl<T: Add<Output = T>> Add for $vector<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $vector { $($component: self.$component + other.$component),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $vector<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $vector { $($component: self.$component - other.$component),+ }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $vector<T> {
+            type Output = Self;
+
+            fn mul(self, scalar: T) -> Self {
+                $vector { $($component: self.$component * scalar),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $vector<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $vector { $($component: -self.$component),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $vector<T> {
+            fn add_assign(&mut self, other: Self) {
+                $(self.$component += other.$component;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $vector<T> {
+            fn sub_assign(&mut self, other: Self) {
+                $(self.$component -= other.$component;)+
+            }
+        }
+
+        impl<T: Zero> Zero for $vector<T> {
+            fn zero() -> Self {
+                $vector { $($component: T::zero()),+ }
+            }
+
+            fn is_zero(&self) -> bool {
+                $(self.$component.is_zero())&&+
+            }
+        }
+    };
+}
+
+impl_vector_ops!(Vec2 { x, y });
+impl_vector_ops!(Vec3 { x, y, z });
+
+impl<T: fmt::Display> fmt::Display for Vec2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vec3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn does_vec2_arithmetic() {
+        let a = Vec2::new(3, -4);
+        let b = Vec2::new(-1, 2);
+        assert_eq!(a + b, Vec2::new(2, -2));
+        assert_eq!(a + b, b + a);
+        assert_eq!(a - b, Vec2::new(4, -6));
+        assert_eq!(a - b, -(b - a));
+        assert_eq!(a * 3, Vec2::new(9, -12));
+        assert_eq!((a + b) * 5, a * 5 + b * 5);
+        assert_eq!(-a, Vec2::new(-3, 4));
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, a + b);
+        c -= b;
+        assert_eq!(c, a);
+
+        assert_eq!(Vec2::zero(), Vec2::new(0, 0));
+        assert_eq!(a + Vec2::zero(), a);
+        assert!((a - a).is_zero());
+        assert!(!a.is_zero());
+    }
+
+    #[test]
+    fn does_vec3_arithmetic() {
+        let a = Vec3::new(1i64, -2, 3);
+        let b = Vec3::new(-4, 5, 6);
+        assert_eq!(a + b, Vec3::new(-3, 3, 9));
+        assert_eq!(a + b, b + a);
+        assert_eq!(a - b, Vec3::new(5, -7, -3));
+        assert_eq!(a - b, -(b - a));
+        assert_eq!(b * -2, Vec3::new(8, -10, -12));
+        assert_eq!((a + b) * 7, a * 7 + b * 7);
+        assert_eq!(a * 2 - a - a, Vec3::zero());
+        assert_eq!(-a, Vec3::new(-1, 2, -3));
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, a + b);
+        c -= b;
+        assert_eq!(c, a);
+
+        assert_eq!(Vec3::<i64>::zero(), Vec3::default());
+        assert!((a + -a).is_zero());
+        assert!(!Vec3::new(0, 0, 1).is_zero());
+    }
+
+    #[test]
+    fn displays_vectors() {
+        assert_eq!(Vec2::new(-1, 2).to_string(), "(-1, 2)");
+        assert_eq!(Vec3::new(1, -2, 3).to_string(), "(1, -2, 3)");
+    }
+}
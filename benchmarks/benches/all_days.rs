@@ -0,0 +1,104 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use benchmarks::{read_input, report, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator::new();
+
+fn bench_day<A, B>(c: &mut Criterion, day: u32, part1: fn(&str) -> A, part2: fn(&str) -> B) {
+    let input = read_input(day);
+
+    report(&format!("day{} part 1", day), &ALLOCATOR, || part1(&input));
+    report(&format!("day{} part 2", day), &ALLOCATOR, || part2(&input));
+
+    let mut group = c.benchmark_group(format!("day{}", day));
+    group.sample_size(10);
+    group.bench_function("solve_part1", |b| b.iter(|| part1(black_box(&input))));
+    group.bench_function("solve_part2", |b| b.iter(|| part2(black_box(&input))));
+    group.finish();
+}
+
+fn bench_all_days(c: &mut Criterion) {
+    bench_day(
+        c,
+        1,
+        |input| day1::solve_part1_from_str(input).unwrap(),
+        |input| day1::solve_part2_from_str(input).unwrap(),
+    );
+    bench_day(c, 2, day2::solve_part1_from_str, day2::solve_part2_from_str);
+    bench_day(
+        c,
+        3,
+        |input| day3::solve_part1_from_str(input).unwrap(),
+        |input| day3::solve_part2_from_str(input).unwrap(),
+    );
+    bench_day(
+        c,
+        4,
+        |input| day4::solve_part1_from_str(input).unwrap(),
+        |input| day4::solve_part2_from_str(input).unwrap(),
+    );
+    bench_day(
+        c,
+        5,
+        |input| day5::solve_part1_from_str(input).unwrap(),
+        |input| day5::solve_part2_from_str(input).unwrap(),
+    );
+    bench_day(
+        c,
+        6,
+        |input| day6::solve_part1_from_str(input).unwrap(),
+        |input| day6::solve_part2_from_str(input).unwrap(),
+    );
+    bench_day(
+        c,
+        7,
+        |input| day7::solve_part1_from_str(input).unwrap(),
+        |input| day7::solve_part2_from_str(input).unwrap(),
+    );
+    bench_day(
+        c,
+        8,
+        |input| day8::solve_part1_from_str(input).unwrap(),
+        |input| day8::solve_part2_from_str(input).unwrap(),
+    );
+    bench_day(
+        c,
+        9,
+        |input| day9::solve_part1_from_str(input).unwrap(),
+        |input| day9::solve_part2_from_str(input).unwrap(),
+    );
+    bench_day(
+        c,
+        10,
+        |input| day10::solve_part1_from_str(input).unwrap(),
+        |input| day10::solve_part2_from_str(input).unwrap(),
+    );
+    bench_day(
+        c,
+        11,
+        |input| day11::solve_part1_from_str(input).unwrap(),
+        |input| day11::solve_part2_from_str(input).unwrap(),
+    );
+    bench_day(
+        c,
+        12,
+        |input| day12::solve_part1_from_str(input).unwrap(),
+        |input| day12::solve_part2_from_str(input).unwrap(),
+    );
+    bench_day(
+        c,
+        13,
+        |input| day13::solve_part1_from_str(input).unwrap(),
+        |input| day13::solve_part2_from_str(input).unwrap(),
+    );
+    bench_day(
+        c,
+        14,
+        |input| day14::solve_part1_from_str(input).unwrap(),
+        |input| day14::solve_part2_from_str(input).unwrap(),
+    );
+}
+
+criterion_group!(benches, bench_all_days);
+criterion_main!(benches);
@@ -0,0 +1,40 @@
+use std::fs;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// the largest example from the puzzle, with 300 asteroids
+const LARGE_FIELD: &str = "../day10/input/test5.txt";
+
+fn bench_visibility(c: &mut Criterion) {
+    let input = fs::read_to_string(LARGE_FIELD)
+        .unwrap_or_else(|err| panic!("Failed to read {}: {}", LARGE_FIELD, err));
+    let asteroid_field = day10::parse_asteroid_field(&input);
+    let asteroids: Vec<day10::Point> = asteroid_field
+        .compute_visibility_matrix()
+        .into_keys()
+        .copied()
+        .collect();
+
+    let mut group = c.benchmark_group("day10 visibility");
+    group.sample_size(10);
+    group.bench_function("visible_from each asteroid", |b| {
+        b.iter(|| {
+            asteroids
+                .iter()
+                .map(|asteroid| black_box(&asteroid_field).visible_from(asteroid))
+                .max()
+        })
+    });
+    group.bench_function("compute_visibility_matrix", |b| {
+        b.iter(|| {
+            black_box(&asteroid_field)
+                .compute_visibility_matrix()
+                .into_values()
+                .max()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_visibility);
+criterion_main!(benches);
@@ -0,0 +1,72 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fs;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Solutions slower than this are flagged for optimization.
+pub const SLOW_THRESHOLD: Duration = Duration::from_secs(1);
+
+pub fn read_input(day: u32) -> String {
+    let path = format!("../day{}/input/input.txt", day);
+    fs::read_to_string(&path).unwrap_or_else(|err| panic!("Failed to read {}: {}", path, err))
+}
+
+/// Wraps the system allocator to count every allocation made through it.
+pub struct CountingAllocator {
+    allocations: AtomicUsize,
+}
+
+impl CountingAllocator {
+    pub const fn new() -> CountingAllocator {
+        CountingAllocator {
+            allocations: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn allocations(&self) -> usize {
+        self.allocations.load(Ordering::Relaxed)
+    }
+}
+
+impl Default for CountingAllocator {
+    fn default() -> CountingAllocator {
+        CountingAllocator::new()
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Runs `solve` once, printing its wall-clock time and allocation count and flagging it if it
+/// takes longer than `SLOW_THRESHOLD`.
+pub fn report<T>(name: &str, allocator: &CountingAllocator, solve: impl FnOnce() -> T) -> T {
+    let allocations_before = allocator.allocations();
+    let start = Instant::now();
+    let answer = solve();
+    let elapsed = start.elapsed();
+    let allocations = allocator.allocations() - allocations_before;
+
+    let flag = if elapsed > SLOW_THRESHOLD {
+        " (SLOW: needs optimization)"
+    } else {
+        ""
+    };
+    println!(
+        "{}: {:?}, {} allocations{}",
+        name, elapsed, allocations, flag
+    );
+    answer
+}
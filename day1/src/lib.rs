@@ -0,0 +1,148 @@
+use aoc_utils::{parse_numbers, read_string};
+
+pub const INPUT: &str = "input/input.txt";
+
+pub type Result<T> = aoc_utils::Result<T>;
+
+fn parse_masses(s: &str) -> Result<Vec<u64>> {
+    parse_numbers(s, '\n')
+}
+
+fn read_masses(filename: &str) -> Result<Vec<u64>> {
+    parse_masses(&read_string(filename)?)
+}
+
+/// Masses of 8 or less need no fuel rather than a negative amount.
+fn calculate_fuel_requirement(mass: u64) -> u64 {
+    (mass / 3).saturating_sub(2)
+}
+
+/// Fuel for `mass`, plus fuel for that fuel, and so on. The chain stops at the first step that
+/// needs no fuel, i.e. once the mass being fueled is 8 or less so that `mass / 3 - 2` would not
+/// be positive.
+fn calculate_fuel_requirement_including_fuel_mass(mass: u64) -> u64 {
+    let mut fuel = calculate_fuel_requirement(mass);
+    let mut total_requirement = 0;
+
+    while fuel > 0 {
+        total_requirement += fuel;
+        fuel = calculate_fuel_requirement(fuel);
+    }
+
+    total_requirement
+}
+
+fn calculate_fuel_sum(masses: Vec<u64>) -> u64 {
+    let fuel_requirements: Vec<u64> = masses
+        .iter()
+        .map(|mass| calculate_fuel_requirement(*mass))
+        .collect();
+    fuel_requirements.iter().sum()
+}
+
+fn calculate_fuel_sum_including_fuel_mass(masses: Vec<u64>) -> u64 {
+    let fuel_requirements: Vec<u64> = masses
+        .iter()
+        .map(|mass| calculate_fuel_requirement_including_fuel_mass(*mass))
+        .collect();
+    fuel_requirements.iter().sum()
+}
+
+pub fn solve_part1_from_str(input: &str) -> Result<u64> {
+    Ok(calculate_fuel_sum(parse_masses(input)?))
+}
+
+pub fn solve_part2_from_str(input: &str) -> Result<u64> {
+    Ok(calculate_fuel_sum_including_fuel_mass(parse_masses(input)?))
+}
+
+pub fn solve_part1() -> Result<u64> {
+    Ok(calculate_fuel_sum(read_masses(INPUT)?))
+}
+
+pub fn solve_part2() -> Result<u64> {
+    Ok(calculate_fuel_sum_including_fuel_mass(read_masses(INPUT)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::AocError;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn reads_masses() {
+        assert_eq!(read_masses(TEST_INPUT).unwrap(), vec![12, 14, 1969, 100756]);
+    }
+
+    #[test]
+    fn calculates_correct_fuel_requirements() {
+        assert_eq!(calculate_fuel_requirement(12), 2);
+        assert_eq!(calculate_fuel_requirement(14), 2);
+        assert_eq!(calculate_fuel_requirement(1969), 654);
+        assert_eq!(calculate_fuel_requirement(100756), 33583);
+    }
+
+    #[test]
+    fn calculates_correct_fuel_requirements_including_fuel_mass() {
+        assert_eq!(calculate_fuel_requirement_including_fuel_mass(14), 2);
+        assert_eq!(calculate_fuel_requirement_including_fuel_mass(1969), 966);
+        assert_eq!(calculate_fuel_requirement_including_fuel_mass(100756), 50346);
+    }
+
+    #[test]
+    fn needs_no_fuel_for_tiny_masses() {
+        for mass in 0..=8 {
+            assert_eq!(calculate_fuel_requirement(mass), 0);
+        }
+        assert_eq!(calculate_fuel_requirement(9), 1);
+        assert_eq!(solve_part1_from_str("5").unwrap(), 0);
+        assert_eq!(solve_part1_from_str("0\n5\n12").unwrap(), 2);
+    }
+
+    #[test]
+    fn stops_fuel_chain_when_no_fuel_is_needed() {
+        // mass / 3 == 2, so no fuel at all
+        assert_eq!(calculate_fuel_requirement_including_fuel_mass(6), 0);
+        assert_eq!(calculate_fuel_requirement_including_fuel_mass(8), 0);
+        // one fuel, which itself needs none
+        assert_eq!(calculate_fuel_requirement_including_fuel_mass(9), 1);
+        // 5 fuel, whose mass / 3 is 1
+        assert_eq!(calculate_fuel_requirement_including_fuel_mass(21), 5);
+        // 7 fuel, whose mass / 3 is exactly 2
+        assert_eq!(calculate_fuel_requirement_including_fuel_mass(27), 7);
+        assert_eq!(calculate_fuel_requirement_including_fuel_mass(0), 0);
+    }
+
+    #[test]
+    fn calculates_fuel_for_masses_beyond_u32() {
+        let mass = u64::from(u32::MAX);
+        assert_eq!(calculate_fuel_requirement(mass), 1_431_655_763);
+        assert_eq!(calculate_fuel_sum(vec![mass; 4]), 5_726_623_052);
+        assert_eq!(
+            calculate_fuel_requirement_including_fuel_mass(mass),
+            2_147_483_579
+        );
+        assert_eq!(
+            parse_masses("4294967295\n8589934592\n").unwrap(),
+            vec![mass, 8_589_934_592]
+        );
+        assert_eq!(solve_part1_from_str("8589934592").unwrap(), 2_863_311_528);
+    }
+
+    #[test]
+    fn reports_bad_masses_as_parse_errors() {
+        match solve_part1_from_str("12\nfourteen\n1969\n") {
+            Err(AocError::Parse(message)) => assert_eq!(
+                message,
+                "Invalid number \"fourteen\": invalid digit found in string"
+            ),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        assert!(matches!(
+            read_masses("input/missing.txt"),
+            Err(AocError::Io(_))
+        ));
+    }
+}
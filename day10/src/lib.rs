@@ -0,0 +1,441 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use aoc_utils::{gcd, max_by_key_stable, parse_grid_mapped, read_string, AocError};
+
+pub const INPUT: &str = "input/input.txt";
+
+pub type Result<T> = aoc_utils::Result<T>;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Rotation {
+    Clockwise,
+    Counterclockwise,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AsteroidField {
+    asteroids: Vec<Point>,
+}
+
+impl AsteroidField {
+    fn get_lines_of_sight(&self, from_point: &Point) -> HashMap<(i32, i32), VecDeque<&Point>> {
+        let mut lines_of_sight: HashMap<(i32, i32), VecDeque<&Point>> = HashMap::new();
+        for asteroid in self.asteroids.iter() {
+            if from_point != asteroid {
+                let x_dist: i32 = asteroid.x as i32 - from_point.x as i32;
+                let y_dist: i32 = asteroid.y as i32 - from_point.y as i32;
+
+                lines_of_sight
+                    .entry(direction(from_point, asteroid))
+                    .and_modify(|deque| {
+                        let mut insertion_index = None;
+                        for (index, current) in deque.iter().enumerate() {
+                            if (current.x as i32 - from_point.x as i32).abs()
+                                + (current.y as i32 - from_point.y as i32).abs()
+                                > x_dist.abs() + y_dist.abs()
+                            {
+                                insertion_index = Some(index);
+                                break;
+                            }
+                        }
+                        if let Some(index) = insertion_index {
+                            deque.insert(index, asteroid);
+                        } else {
+                            deque.push_back(asteroid);
+                        }
+                    })
+                    .or_insert_with(|| {
+                        let mut deque = VecDeque::new();
+                        deque.push_back(asteroid);
+                        deque
+                    });
+            }
+        }
+        lines_of_sight
+    }
+
+    /// Number of distinct directions from `point` to the other asteroids. Collinear asteroids
+    /// share a direction and only the nearest of them is visible, so this is also how many
+    /// asteroids `point` can see.
+    pub fn distinct_directions(&self, point: &Point) -> usize {
+        self.get_lines_of_sight(point).len()
+    }
+
+    /// The asteroid nearest to `from` on the ray towards `target` that hides `target` from view,
+    /// or `None` if `target` is visible or isn't an asteroid in this field.
+    pub fn blocker(&self, from: &Point, target: &Point) -> Option<Point> {
+        if from == target || !self.asteroids.contains(target) {
+            return None;
+        }
+        let lines_of_sight = self.get_lines_of_sight(from);
+        let nearest = **lines_of_sight.get(&direction(from, target))?.front()?;
+        if nearest == *target {
+            None
+        } else {
+            Some(nearest)
+        }
+    }
+
+    pub fn visible_from(&self, point: &Point) -> usize {
+        self.distinct_directions(point)
+    }
+
+    /// The nearest asteroid in every direction from `point`, in clockwise order from straight up.
+    pub fn visible_asteroids(&self, point: &Point) -> Vec<Point> {
+        let mut visible: Vec<((i32, i32), Point)> = self
+            .get_lines_of_sight(point)
+            .into_iter()
+            .filter_map(|(direction, in_sight)| {
+                in_sight.front().map(|asteroid| (direction, **asteroid))
+            })
+            .collect();
+        visible.sort_by(|a, b| {
+            bearing(&a.0)
+                .partial_cmp(&bearing(&b.0))
+                .unwrap_or(Ordering::Equal)
+        });
+        visible.into_iter().map(|(_, asteroid)| asteroid).collect()
+    }
+
+    /// How many asteroids each asteroid can see. The direction from `b` to `a` is the reverse of
+    /// the direction from `a` to `b`, so each pair is only looked at once.
+    pub fn compute_visibility_matrix(&self) -> HashMap<&Point, usize> {
+        let mut directions: Vec<HashSet<(i32, i32)>> = vec![HashSet::new(); self.asteroids.len()];
+        for (i, a) in self.asteroids.iter().enumerate() {
+            for (j, b) in self.asteroids.iter().enumerate().skip(i + 1) {
+                let (x, y) = direction(a, b);
+                directions[i].insert((x, y));
+                directions[j].insert((-x, -y));
+            }
+        }
+        self.asteroids
+            .iter()
+            .zip(directions)
+            .map(|(asteroid, directions)| (asteroid, directions.len()))
+            .collect()
+    }
+
+    pub fn find_monitoring_station(&self) -> (&Point, usize) {
+        max_by_key_stable(self.compute_visibility_matrix(), |score| score.1)
+            .expect("No asteroid detect scores")
+    }
+
+    pub fn vaporize_asteroids(&self, laser_point: &Point) -> Option<&Point> {
+        self.vaporize_nth(laser_point, 200, Rotation::Clockwise, 0.0)
+    }
+
+    pub fn vaporize_nth(
+        &self,
+        laser_point: &Point,
+        n: usize,
+        rotation: Rotation,
+        start_bearing: f32,
+    ) -> Option<&Point> {
+        let order = self.vaporization_order(laser_point, rotation, start_bearing);
+        n.checked_sub(1).and_then(|index| order.get(index)).copied()
+    }
+
+    pub fn vaporization_order(
+        &self,
+        laser_point: &Point,
+        rotation: Rotation,
+        start_bearing: f32,
+    ) -> Vec<&Point> {
+        let mut order = vec![];
+        let mut lines_of_sight = self.get_lines_of_sight(laser_point);
+        let mut directions: Vec<(i32, i32)> = lines_of_sight.keys().copied().collect();
+        let sweep = |direction: &(i32, i32)| {
+            let offset = bearing(direction) - start_bearing;
+            match rotation {
+                Rotation::Clockwise => offset.rem_euclid(360.0),
+                Rotation::Counterclockwise => (-offset).rem_euclid(360.0),
+            }
+        };
+        directions.sort_by(|a, b| sweep(a).partial_cmp(&sweep(b)).unwrap_or(Ordering::Equal));
+
+        while order.len() < self.asteroids.len() {
+            let vaporized_before = order.len();
+            for direction in directions.iter() {
+                if let Some(in_sight) = lines_of_sight.get_mut(direction) {
+                    if let Some(vaporized_asteroid) = in_sight.pop_front() {
+                        order.push(vaporized_asteroid);
+                    }
+                }
+            }
+            if order.len() == vaporized_before {
+                break;
+            }
+        }
+        order
+    }
+
+    pub fn vaporization_report(&self, laser_point: &Point) -> Vec<(usize, Point)> {
+        self.vaporization_order(laser_point, Rotation::Clockwise, 0.0)
+            .into_iter()
+            .enumerate()
+            .map(|(index, asteroid)| (index + 1, *asteroid))
+            .collect()
+    }
+}
+
+// the step from `from` towards `to` reduced by the gcd of its components, which is shared by
+// every point on the same ray
+fn direction(from: &Point, to: &Point) -> (i32, i32) {
+    let x_dist = to.x as i32 - from.x as i32;
+    let y_dist = to.y as i32 - from.y as i32;
+    let gcd = gcd(x_dist.abs(), y_dist.abs());
+    (x_dist / gcd, y_dist / gcd)
+}
+
+// degrees clockwise from straight up (y increases downwards)
+fn bearing(direction: &(i32, i32)) -> f32 {
+    (direction.0 as f32)
+        .atan2(-direction.1 as f32)
+        .to_degrees()
+        .rem_euclid(360.0)
+}
+
+pub fn parse_asteroid_field(input: &str) -> AsteroidField {
+    let asteroids = parse_grid_mapped(input, |c| c == '#')
+        .iter_cells()
+        .filter(|(_, _, asteroid)| **asteroid)
+        .map(|(x, y, _)| Point { x, y })
+        .collect();
+
+    AsteroidField { asteroids }
+}
+
+pub fn solve_part1_from_str(input: &str) -> Result<usize> {
+    let asteroid_field = parse_asteroid_field(input);
+    Ok(asteroid_field.find_monitoring_station().1)
+}
+
+pub fn solve_part2_from_str(input: &str) -> Result<usize> {
+    let asteroid_field = parse_asteroid_field(input);
+    let vaporized200 = asteroid_field
+        .vaporize_asteroids(&Point { x: 22, y: 25 })
+        .ok_or(AocError::NoSolutionFound)?;
+    Ok(vaporized200.x * 100 + vaporized200.y)
+}
+
+pub fn solve_part1() -> Result<usize> {
+    solve_part1_from_str(&read_string(INPUT)?)
+}
+
+pub fn solve_part2() -> Result<usize> {
+    solve_part2_from_str(&read_string(INPUT)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT1: &str = "input/test1.txt";
+    const TEST_INPUT2: &str = "input/test2.txt";
+    const TEST_INPUT3: &str = "input/test3.txt";
+    const TEST_INPUT4: &str = "input/test4.txt";
+    const TEST_INPUT5: &str = "input/test5.txt";
+    const TEST_INPUT7: &str = "input/test7.txt";
+
+    fn read_asteroid_field(filename: &str) -> Result<AsteroidField> {
+        Ok(parse_asteroid_field(&read_string(filename)?))
+    }
+
+    #[test]
+    fn reads_asteroid_field() {
+        assert_eq!(
+            read_asteroid_field(TEST_INPUT1).unwrap(),
+            AsteroidField {
+                asteroids: vec![
+                    Point { x: 1, y: 0 },
+                    Point { x: 4, y: 0 },
+                    Point { x: 0, y: 2 },
+                    Point { x: 1, y: 2 },
+                    Point { x: 2, y: 2 },
+                    Point { x: 3, y: 2 },
+                    Point { x: 4, y: 2 },
+                    Point { x: 4, y: 3 },
+                    Point { x: 3, y: 4 },
+                    Point { x: 4, y: 4 },
+                ]
+            },
+        )
+    }
+
+    #[test]
+    fn finds_monitoring_stations() {
+        for (input, monitoring_point) in [
+            (TEST_INPUT1, Point { x: 3, y: 4 }),
+            (TEST_INPUT2, Point { x: 5, y: 8 }),
+            (TEST_INPUT3, Point { x: 1, y: 2 }),
+            (TEST_INPUT4, Point { x: 6, y: 3 }),
+            (TEST_INPUT5, Point { x: 11, y: 13 }),
+        ]
+        .iter()
+        {
+            let asteroid_field = read_asteroid_field(input).unwrap();
+            assert_eq!(asteroid_field.find_monitoring_station().0, monitoring_point);
+        }
+    }
+
+    #[test]
+    fn computes_visibility_matrix() {
+        for input in [TEST_INPUT1, TEST_INPUT2, TEST_INPUT5].iter() {
+            let asteroid_field = read_asteroid_field(input).unwrap();
+            let visibility = asteroid_field.compute_visibility_matrix();
+            assert_eq!(visibility.len(), asteroid_field.asteroids.len());
+            for (asteroid, visible) in visibility {
+                assert_eq!(visible, asteroid_field.visible_from(asteroid));
+            }
+        }
+    }
+
+    #[test]
+    fn counts_collinear_asteroids_as_one_direction() {
+        let asteroid_field = parse_asteroid_field("#...\n.#..\n..#.\n...#\n");
+        assert_eq!(asteroid_field.distinct_directions(&Point { x: 0, y: 0 }), 1);
+        assert_eq!(asteroid_field.distinct_directions(&Point { x: 1, y: 1 }), 2);
+
+        let asteroid_field = parse_asteroid_field("#.#.#\n..#..\n");
+        assert_eq!(asteroid_field.distinct_directions(&Point { x: 0, y: 0 }), 2);
+        assert_eq!(asteroid_field.distinct_directions(&Point { x: 2, y: 1 }), 3);
+    }
+
+    #[test]
+    fn finds_blocking_asteroids() {
+        let asteroid_field = parse_asteroid_field("#...\n.#..\n..#.\n...#\n");
+        let from = Point { x: 0, y: 0 };
+        assert_eq!(
+            asteroid_field.blocker(&from, &Point { x: 3, y: 3 }),
+            Some(Point { x: 1, y: 1 })
+        );
+        assert_eq!(
+            asteroid_field.blocker(&from, &Point { x: 2, y: 2 }),
+            Some(Point { x: 1, y: 1 })
+        );
+        assert_eq!(asteroid_field.blocker(&from, &Point { x: 1, y: 1 }), None);
+        assert_eq!(asteroid_field.blocker(&from, &from), None);
+        // not an asteroid
+        assert_eq!(asteroid_field.blocker(&from, &Point { x: 3, y: 0 }), None);
+
+        let asteroid_field = read_asteroid_field(TEST_INPUT1).unwrap();
+        assert_eq!(
+            asteroid_field.blocker(&Point { x: 3, y: 4 }, &Point { x: 1, y: 0 }),
+            Some(Point { x: 2, y: 2 })
+        );
+    }
+
+    #[test]
+    fn lists_visible_asteroids() {
+        let asteroid_field = read_asteroid_field(TEST_INPUT1).unwrap();
+        let station = Point { x: 3, y: 4 };
+        let visible = asteroid_field.visible_asteroids(&station);
+        assert_eq!(visible.len(), asteroid_field.visible_from(&station));
+        assert_eq!(
+            visible,
+            vec![
+                Point { x: 3, y: 2 },
+                Point { x: 4, y: 0 },
+                Point { x: 4, y: 2 },
+                Point { x: 4, y: 3 },
+                Point { x: 4, y: 4 },
+                Point { x: 0, y: 2 },
+                Point { x: 1, y: 2 },
+                Point { x: 2, y: 2 },
+            ]
+        );
+        // hidden behind (2, 2)
+        assert!(!visible.contains(&Point { x: 1, y: 0 }));
+
+        let asteroid_field = read_asteroid_field(TEST_INPUT5).unwrap();
+        let station = Point { x: 11, y: 13 };
+        assert_eq!(asteroid_field.visible_asteroids(&station).len(), 210);
+    }
+
+    #[test]
+    fn queries_field_after_vaporizing() {
+        let asteroid_field = read_asteroid_field(TEST_INPUT5).unwrap();
+        assert_eq!(
+            asteroid_field.vaporize_asteroids(&Point { x: 11, y: 13 }),
+            Some(&Point { x: 8, y: 2 })
+        );
+        assert_eq!(asteroid_field.asteroids.len(), 300);
+        assert_eq!(asteroid_field.visible_from(&Point { x: 11, y: 13 }), 210);
+        let other_point = Point { x: 3, y: 4 };
+        assert_eq!(
+            asteroid_field.visible_from(&other_point),
+            read_asteroid_field(TEST_INPUT5)
+                .unwrap()
+                .visible_from(&other_point)
+        );
+    }
+
+    #[test]
+    fn vaporizes_in_either_rotation() {
+        let asteroid_field = read_asteroid_field(TEST_INPUT7).unwrap();
+        let laser_point = Point { x: 2, y: 2 };
+        let order = |rotation, start_bearing| {
+            (1..=8)
+                .map(|n| {
+                    *asteroid_field
+                        .vaporize_nth(&laser_point, n, rotation, start_bearing)
+                        .unwrap()
+                })
+                .collect::<Vec<Point>>()
+        };
+
+        let clockwise = order(Rotation::Clockwise, 0.0);
+        let counterclockwise = order(Rotation::Counterclockwise, 0.0);
+        assert_eq!(
+            clockwise,
+            vec![
+                Point { x: 2, y: 1 },
+                Point { x: 3, y: 2 },
+                Point { x: 2, y: 3 },
+                Point { x: 1, y: 2 },
+                Point { x: 2, y: 0 },
+                Point { x: 4, y: 2 },
+                Point { x: 2, y: 4 },
+                Point { x: 0, y: 2 },
+            ]
+        );
+        assert_eq!(
+            counterclockwise,
+            clockwise
+                .iter()
+                .map(|point| Point {
+                    x: 4 - point.x,
+                    y: point.y
+                })
+                .collect::<Vec<Point>>()
+        );
+        assert_eq!(order(Rotation::Clockwise, 90.0)[0], Point { x: 3, y: 2 });
+        assert_eq!(
+            asteroid_field.vaporize_nth(&laser_point, 9, Rotation::Clockwise, 0.0),
+            None
+        );
+    }
+
+    #[test]
+    fn reports_vaporization_order() {
+        let asteroid_field = read_asteroid_field(TEST_INPUT5).unwrap();
+        let report = asteroid_field.vaporization_report(&Point { x: 11, y: 13 });
+        assert_eq!(
+            report[..3],
+            [
+                (1, Point { x: 11, y: 12 }),
+                (2, Point { x: 12, y: 1 }),
+                (3, Point { x: 12, y: 2 }),
+            ]
+        );
+        assert_eq!(report[199], (200, Point { x: 8, y: 2 }));
+        assert_eq!(report.len(), 299);
+    }
+}
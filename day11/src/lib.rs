@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::result;
+
+use aoc_utils::{read_string, BoundingBox, Direction4, Point2D};
+use intcode::{narrow_u8, Intcode, StopReason};
+use num_enum::TryFromPrimitive;
+
+pub const INPUT: &str = "input/input.txt";
+
+pub type Result<T> = result::Result<T, Box<dyn Error>>;
+
+/// Far more panels than any real registration needs; a program still painting after this many is
+/// assumed to never halt.
+const MAX_PAINTS: usize = 100_000;
+
+#[derive(TryFromPrimitive, Debug, PartialEq, Clone, Copy)]
+#[repr(u8)]
+enum Color {
+    Black = 0,
+    White = 1,
+}
+
+#[derive(TryFromPrimitive, Clone, Copy)]
+#[repr(u8)]
+enum Turn {
+    Left = 0,
+    Right = 1,
+}
+
+struct Robot {
+    intcode: Intcode,
+    position: Point2D<i64>,
+    direction: Direction4,
+}
+
+impl Robot {
+    fn new(intcode: Intcode) -> Robot {
+        Robot {
+            intcode: intcode.clone(),
+            position: Point2D { x: 0, y: 0 },
+            direction: Direction4::North,
+        }
+    }
+
+    fn turn_and_move(&mut self, turn: Turn) {
+        self.direction = match turn {
+            Turn::Left => self.direction.turn_left(),
+            Turn::Right => self.direction.turn_right(),
+        };
+        self.position = self.position + Point2D::from(self.direction.to_delta());
+    }
+}
+
+struct Hull {
+    panels: HashMap<Point2D<i64>, Color>,
+}
+
+impl Hull {
+    fn new() -> Hull {
+        Hull {
+            panels: HashMap::new(),
+        }
+    }
+
+    /// Unpainted panels are black.
+    fn color_at(&self, coord: Point2D<i64>) -> Color {
+        *self.panels.get(&coord).unwrap_or(&Color::Black)
+    }
+
+    fn paint_registration(&mut self, intcode: Intcode, start_color: Color) -> Result<()> {
+        let mut robot = Robot::new(intcode);
+        let mut current_panel = start_color;
+        for _ in 0..MAX_PAINTS {
+            let (output, stop_reason) = robot.intcode.run(&[current_panel as i64], Some(2))?;
+            match stop_reason {
+                StopReason::Halted => return Ok(()),
+                StopReason::NeedInput => {
+                    return Err(From::from("program requested input but none available"));
+                }
+                StopReason::OutputLimit => {}
+            }
+            let color = Color::try_from(narrow_u8(output[0])?)?;
+            let turn = Turn::try_from(narrow_u8(output[1])?)?;
+
+            self.panels.insert(robot.position, color);
+            robot.turn_and_move(turn);
+            current_panel = self.color_at(robot.position);
+        }
+        Err(From::from(format!(
+            "program did not halt after painting {} panels",
+            MAX_PAINTS
+        )))
+    }
+}
+
+impl fmt::Display for Hull {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bounds = BoundingBox::from_points(self.panels.keys().copied()).unwrap_or_default();
+        for y in bounds.min().y..=bounds.max().y {
+            let mut row_string = String::new();
+            for x in bounds.min().x..=bounds.max().x {
+                row_string += match self.color_at(Point2D { x, y }) {
+                    Color::Black => ".",
+                    Color::White => "#",
+                };
+            }
+            writeln!(f, "{}", row_string)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn solve_part1_from_str(input: &str) -> Result<usize> {
+    let intcode: Intcode = input.parse()?;
+    let mut hull = Hull::new();
+    hull.paint_registration(intcode, Color::Black)?;
+    Ok(hull.panels.len())
+}
+
+pub fn solve_part2_from_str(input: &str) -> Result<String> {
+    let intcode: Intcode = input.parse()?;
+    let mut hull = Hull::new();
+    hull.paint_registration(intcode, Color::White)?;
+    Ok(format!("\n{}", hull))
+}
+
+pub fn solve_part1() -> Result<usize> {
+    solve_part1_from_str(&read_string(INPUT)?)
+}
+
+pub fn solve_part2() -> Result<String> {
+    solve_part2_from_str(&read_string(INPUT)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::assert_grid_eq;
+
+    #[test]
+    fn displays_painted_hull() {
+        let mut hull = Hull::new();
+        hull.paint_registration(
+            "3,100,104,1,104,1,3,100,104,1,104,1,3,100,104,1,104,1,99"
+                .parse()
+                .unwrap(),
+            Color::Black,
+        )
+        .unwrap();
+        assert_grid_eq(&hull.to_string(), "##\n.#\n");
+    }
+
+    #[test]
+    fn gets_color_at_coordinate() {
+        let mut hull = Hull::new();
+        hull.paint_registration(
+            "3,100,104,0,104,1,3,100,104,1,104,1,3,100,99"
+                .parse()
+                .unwrap(),
+            Color::White,
+        )
+        .unwrap();
+        assert_eq!(hull.panels.len(), 2);
+        assert_eq!(hull.color_at(Point2D::new(0, 0)), Color::Black);
+        assert_eq!(hull.color_at(Point2D::new(1, 0)), Color::White);
+        assert_eq!(hull.color_at(Point2D::new(1, 1)), Color::Black);
+        assert_eq!(hull.color_at(Point2D::new(-4, 7)), Color::Black);
+    }
+
+    #[test]
+    fn errors_when_input_is_requested_but_unavailable() {
+        let mut hull = Hull::new();
+        assert_eq!(
+            hull.paint_registration("3,0,3,0,99".parse().unwrap(), Color::Black)
+                .unwrap_err()
+                .to_string(),
+            "program requested input but none available"
+        );
+    }
+
+    #[test]
+    fn errors_when_program_never_halts() {
+        let mut hull = Hull::new();
+        assert_eq!(
+            hull.paint_registration("3,100,104,1,104,0,1105,1,0".parse().unwrap(), Color::Black)
+                .unwrap_err()
+                .to_string(),
+            "program did not halt after painting 100000 panels"
+        );
+    }
+
+    #[test]
+    fn rejects_outputs_outside_u8() {
+        let mut hull = Hull::new();
+        assert_eq!(
+            hull.paint_registration("3,100,104,256,104,0,99".parse().unwrap(), Color::Black)
+                .unwrap_err()
+                .to_string(),
+            "Output 256 does not fit in a u8"
+        );
+    }
+}
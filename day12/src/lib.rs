@@ -0,0 +1,548 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+use std::thread;
+
+use aoc_utils::{lcm, read_string, sign, AocError, Vec3};
+
+pub type Result<T> = aoc_utils::Result<T>;
+
+pub const INPUT: &str = "input/input.txt";
+const FINGERPRINT_SEED: u64 = 0xcbf2_9ce4_8422_2325;
+const FINGERPRINT_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+pub type Vector = Vec3<i64>;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Body {
+    position: Vector,
+    velocity: Vector,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct NBody {
+    bodies: Vec<Body>,
+}
+
+impl FromStr for Body {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Body> {
+        let malformed = || AocError::InvalidInput(format!("Malformed scan {:?}", s));
+        // hand-edited scans sometimes have extra spaces, e.g. `<x = 2, y =-10, z= -7>`, but the
+        // labels, separators and numbers themselves must be intact
+        let coordinates = s
+            .trim()
+            .strip_prefix('<')
+            .and_then(|s| s.strip_suffix('>'))
+            .ok_or_else(malformed)?
+            .split(',')
+            .collect::<Vec<_>>();
+        if coordinates.len() != 3 {
+            return Err(malformed());
+        }
+        let mut position = [0; 3];
+        for ((coordinate, label), value) in coordinates
+            .into_iter()
+            .zip(["x", "y", "z"])
+            .zip(position.iter_mut())
+        {
+            match coordinate.split_once('=') {
+                Some((name, number)) if name.trim() == label => *value = number.trim().parse()?,
+                _ => return Err(malformed()),
+            }
+        }
+        let [x, y, z] = position;
+        Ok(Body::new(x, y, z))
+    }
+}
+
+impl Body {
+    fn new(x: i64, y: i64, z: i64) -> Body {
+        Body {
+            position: Vector { x, y, z },
+            velocity: Vector::default(),
+        }
+    }
+
+    fn add_gravity(&self, gravity: &mut Vector, other: &Self) {
+        gravity.x += sign(other.position.x - self.position.x);
+        gravity.y += sign(other.position.y - self.position.y);
+        gravity.z += sign(other.position.z - self.position.z);
+    }
+}
+
+impl NBody {
+    pub fn run_step(&mut self) {
+        let mut gravities = Vec::new();
+        for body in self.bodies.iter() {
+            let mut gravity = Vector::default();
+            for other_body in self.bodies.iter() {
+                body.add_gravity(&mut gravity, other_body);
+            }
+            gravities.push(gravity);
+        }
+
+        for (index, gravity) in gravities.into_iter().enumerate() {
+            self.bodies[index].velocity += gravity;
+            let velocity = self.bodies[index].velocity;
+            self.bodies[index].position += velocity;
+        }
+    }
+
+    /// A copy of this system after `n` steps, leaving `self` untouched.
+    pub fn clone_at_step(&self, n: u64) -> NBody {
+        let mut nbody = self.clone();
+        for _ in 0..n {
+            nbody.run_step();
+        }
+        nbody
+    }
+
+    pub fn total_energy(&self) -> i64 {
+        let mut total_energy = 0;
+        for body in self.bodies.iter() {
+            let potential_energy =
+                body.position.x.abs() + body.position.y.abs() + body.position.z.abs();
+            let kinetic_energy =
+                body.velocity.x.abs() + body.velocity.y.abs() + body.velocity.z.abs();
+            total_energy += potential_energy * kinetic_energy;
+        }
+        total_energy
+    }
+
+    pub fn total_momentum(&self) -> Vector {
+        let mut total_momentum = Vector::default();
+        for body in self.bodies.iter() {
+            total_momentum += body.velocity;
+        }
+        total_momentum
+    }
+
+    /// Position and velocity of every body along one axis (0 = x, 1 = y, 2 = z).
+    fn axis(&self, axis: usize) -> Vec<(i64, i64)> {
+        let component = |vector: &Vector| [vector.x, vector.y, vector.z][axis];
+        self.bodies
+            .iter()
+            .map(|body| (component(&body.position), component(&body.velocity)))
+            .collect()
+    }
+
+    /// Rolling hash of all positions and velocities along one axis (0 = x, 1 = y, 2 = z).
+    pub fn axis_fingerprint(&self, axis: usize) -> u64 {
+        fingerprint(&self.axis(axis))
+    }
+}
+
+/// Rolling hash of all positions and velocities along one axis.
+fn fingerprint(bodies_1d: &[(i64, i64)]) -> u64 {
+    bodies_1d
+        .iter()
+        .fold(FINGERPRINT_SEED, |hash, (position, velocity)| {
+            let hash = hash
+                .wrapping_mul(FINGERPRINT_PRIME)
+                .wrapping_add(*position as u64);
+            hash.wrapping_mul(FINGERPRINT_PRIME)
+                .wrapping_add(*velocity as u64)
+        })
+}
+
+/// `NBody::run_step` restricted to one axis, which never depends on the other two.
+fn run_axis_step(bodies_1d: &mut [(i64, i64)]) {
+    let gravities: Vec<i64> = bodies_1d
+        .iter()
+        .map(|(position, _)| {
+            bodies_1d
+                .iter()
+                .map(|(other_position, _)| sign(other_position - position))
+                .sum()
+        })
+        .collect();
+
+    for ((position, velocity), gravity) in bodies_1d.iter_mut().zip(gravities) {
+        *velocity += gravity;
+        *position += *velocity;
+    }
+}
+
+/// Number of steps until the bodies along one axis return to a state they've already been in.
+fn axis_cycle(bodies_1d: &[(i64, i64)]) -> u64 {
+    let mut bodies_1d = bodies_1d.to_vec();
+    let mut states: HashSet<u64> = HashSet::new();
+    let mut step_count = 0;
+    while states.insert(fingerprint(&bodies_1d)) {
+        run_axis_step(&mut bodies_1d);
+        step_count += 1;
+    }
+    step_count
+}
+
+pub fn parse_moon_scan(input: &str) -> Result<NBody> {
+    let mut moons = vec![];
+
+    for line in input.lines() {
+        moons.push(line.parse()?);
+    }
+
+    Ok(NBody { bodies: moons })
+}
+
+pub fn solve_part1_from_str(input: &str) -> Result<i64> {
+    Ok(parse_moon_scan(input)?.clone_at_step(1000).total_energy())
+}
+
+pub fn solve_part2_from_str(input: &str) -> Result<u64> {
+    let nbody = parse_moon_scan(input)?;
+    // the axes evolve independently, so each one's cycle is found on its own thread
+    let simulations: Vec<thread::JoinHandle<u64>> = (0..3)
+        .map(|axis| {
+            let bodies_1d = nbody.axis(axis);
+            thread::spawn(move || axis_cycle(&bodies_1d))
+        })
+        .collect();
+
+    let mut steps = 1;
+    for simulation in simulations {
+        let axis_steps = simulation
+            .join()
+            .map_err(|_| AocError::InvalidInput("Axis simulation panicked".to_string()))?;
+        steps = lcm(steps, axis_steps);
+    }
+    Ok(steps)
+}
+
+pub fn solve_part1(filename: &str) -> Result<i64> {
+    solve_part1_from_str(&read_string(filename)?)
+}
+
+pub fn solve_part2(filename: &str) -> Result<u64> {
+    solve_part2_from_str(&read_string(filename)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::parse_all_integers;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    const TEST_INPUT1: &str = "input/test1.txt";
+    const TEST_INPUT2: &str = "input/test2.txt";
+    const TEST_STEPS1: &str = "input/test1_steps.txt";
+
+    fn read_moon_scan(filename: &str) -> Result<NBody> {
+        parse_moon_scan(&read_string(filename)?)
+    }
+
+    /// Parses the puzzle's "After N steps:" tables, one `NBody` per table in step order.
+    fn read_step_tables(filename: &str) -> Vec<NBody> {
+        read_string(filename)
+            .unwrap()
+            .split("After ")
+            .filter(|table| !table.trim().is_empty())
+            .map(|table| NBody {
+                bodies: table
+                    .lines()
+                    .skip(1)
+                    .filter(|line| !line.trim().is_empty())
+                    .map(|line| match parse_all_integers::<i64>(line).unwrap()[..] {
+                        [x, y, z, vx, vy, vz] => Body {
+                            position: Vector { x, y, z },
+                            velocity: Vector {
+                                x: vx,
+                                y: vy,
+                                z: vz,
+                            },
+                        },
+                        _ => panic!("malformed step table line {:?}", line),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+    fn nbody_1() -> NBody {
+        NBody {
+            bodies: vec![
+                Body {
+                    position: Vector { x: -1, y: 0, z: 2 },
+                    velocity: Vector { x: 0, y: 0, z: 0 },
+                },
+                Body {
+                    position: Vector {
+                        x: 2,
+                        y: -10,
+                        z: -7,
+                    },
+                    velocity: Vector { x: 0, y: 0, z: 0 },
+                },
+                Body {
+                    position: Vector { x: 4, y: -8, z: 8 },
+                    velocity: Vector { x: 0, y: 0, z: 0 },
+                },
+                Body {
+                    position: Vector { x: 3, y: 5, z: -1 },
+                    velocity: Vector { x: 0, y: 0, z: 0 },
+                },
+            ],
+        }
+    }
+    fn nbody_1_after_10_steps() -> NBody {
+        NBody {
+            bodies: vec![
+                Body {
+                    position: Vector { x: 2, y: 1, z: -3 },
+                    velocity: Vector { x: -3, y: -2, z: 1 },
+                },
+                Body {
+                    position: Vector { x: 1, y: -8, z: 0 },
+                    velocity: Vector { x: -1, y: 1, z: 3 },
+                },
+                Body {
+                    position: Vector { x: 3, y: -6, z: 1 },
+                    velocity: Vector { x: 3, y: 2, z: -3 },
+                },
+                Body {
+                    position: Vector { x: 2, y: 0, z: 4 },
+                    velocity: Vector { x: 1, y: -1, z: -1 },
+                },
+            ],
+        }
+    }
+    fn nbody_2() -> NBody {
+        NBody {
+            bodies: vec![
+                Body {
+                    position: Vector {
+                        x: -8,
+                        y: -10,
+                        z: 0,
+                    },
+                    velocity: Vector { x: 0, y: 0, z: 0 },
+                },
+                Body {
+                    position: Vector { x: 5, y: 5, z: 10 },
+                    velocity: Vector { x: 0, y: 0, z: 0 },
+                },
+                Body {
+                    position: Vector { x: 2, y: -7, z: 3 },
+                    velocity: Vector { x: 0, y: 0, z: 0 },
+                },
+                Body {
+                    position: Vector { x: 9, y: -8, z: -3 },
+                    velocity: Vector { x: 0, y: 0, z: 0 },
+                },
+            ],
+        }
+    }
+    fn nbody_2_after_100_steps() -> NBody {
+        NBody {
+            bodies: vec![
+                Body {
+                    position: Vector {
+                        x: 8,
+                        y: -12,
+                        z: -9,
+                    },
+                    velocity: Vector { x: -7, y: 3, z: 0 },
+                },
+                Body {
+                    position: Vector {
+                        x: 13,
+                        y: 16,
+                        z: -3,
+                    },
+                    velocity: Vector {
+                        x: 3,
+                        y: -11,
+                        z: -5,
+                    },
+                },
+                Body {
+                    position: Vector {
+                        x: -29,
+                        y: -11,
+                        z: -1,
+                    },
+                    velocity: Vector { x: -3, y: 7, z: 4 },
+                },
+                Body {
+                    position: Vector {
+                        x: 16,
+                        y: -13,
+                        z: 23,
+                    },
+                    velocity: Vector { x: 7, y: 1, z: 1 },
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn reads_moon_scan_file() {
+        assert_eq!(read_moon_scan(TEST_INPUT1).unwrap(), nbody_1());
+        assert_eq!(read_moon_scan(TEST_INPUT2).unwrap(), nbody_2());
+    }
+
+    #[test]
+    fn parses_spaced_coordinates() {
+        let strict: Body = "<x=2, y=-10, z=-7>".parse().unwrap();
+        assert_eq!("<x = 2, y =-10, z= -7>".parse::<Body>().unwrap(), strict);
+        assert_eq!("  <x=2 ,y=-10 , z=-7 > ".parse::<Body>().unwrap(), strict);
+    }
+
+    #[test]
+    fn rejects_malformed_scans() {
+        assert!("<x=2, y=-10>".parse::<Body>().is_err());
+        assert!("<x=2, y=- 10, z=-7>".parse::<Body>().is_err());
+        assert!("<x=2 y=-10 z=-7>".parse::<Body>().is_err());
+        assert!("<x=2, y=-10, z=-7, w=1>".parse::<Body>().is_err());
+        assert!("<z=1, y=2, x=3>".parse::<Body>().is_err());
+        assert!("<x=99999999999999999999, y=1, z=2>"
+            .parse::<Body>()
+            .is_err());
+        assert!("<x=2, y=-10, z=-7> <x=1, y=1, z=1>"
+            .parse::<Body>()
+            .is_err());
+    }
+
+    #[test]
+    fn matches_published_steps() {
+        let tables = read_step_tables(TEST_STEPS1);
+        assert_eq!(tables.len(), 6);
+        let mut nbody = read_moon_scan(TEST_INPUT1).unwrap();
+        for (step, expected) in tables.iter().enumerate() {
+            assert_eq!(&nbody, expected, "state after {} steps", step);
+            nbody.run_step();
+        }
+    }
+
+    #[test]
+    fn runs_10_steps() {
+        let mut nbody = read_moon_scan(TEST_INPUT1).unwrap();
+        for _ in 0..10 {
+            nbody.run_step();
+        }
+        assert_eq!(nbody, nbody_1_after_10_steps());
+    }
+
+    #[test]
+    fn runs_100_steps() {
+        let mut nbody = read_moon_scan(TEST_INPUT2).unwrap();
+        for _ in 0..100 {
+            nbody.run_step();
+        }
+        assert_eq!(nbody, nbody_2_after_100_steps());
+    }
+
+    #[test]
+    fn clones_at_step() {
+        let nbody = read_moon_scan(TEST_INPUT1).unwrap();
+        assert_eq!(nbody.clone_at_step(10), nbody_1_after_10_steps());
+        assert_eq!(nbody, nbody_1());
+        assert_eq!(nbody.clone_at_step(0), nbody);
+    }
+
+    #[test]
+    fn calculates_total_energy_after_10_steps() {
+        let mut nbody = read_moon_scan(TEST_INPUT1).unwrap();
+        for _ in 0..10 {
+            nbody.run_step();
+        }
+        assert_eq!(nbody.total_energy(), 179);
+    }
+
+    #[test]
+    fn calculates_total_energy_after_100_steps() {
+        let mut nbody = read_moon_scan(TEST_INPUT2).unwrap();
+        for _ in 0..100 {
+            nbody.run_step();
+        }
+        assert_eq!(nbody.total_energy(), 1940);
+    }
+
+    #[test]
+    fn conserves_momentum() {
+        for input in [TEST_INPUT1, TEST_INPUT2].iter() {
+            let mut nbody = read_moon_scan(input).unwrap();
+            for _ in 0..100 {
+                nbody.run_step();
+                assert_eq!(nbody.total_momentum(), Vector::default());
+            }
+        }
+    }
+
+    #[test]
+    fn fingerprints_distinct_axis_states() {
+        let mut nbody = read_moon_scan(TEST_INPUT1).unwrap();
+        let initial = nbody.axis_fingerprint(0);
+        assert_eq!(initial, fingerprint(&nbody.axis(0)));
+        assert_ne!(initial, nbody.axis_fingerprint(1));
+        assert_ne!(initial, nbody.axis_fingerprint(2));
+        assert_ne!(nbody.axis_fingerprint(1), nbody.axis_fingerprint(2));
+
+        assert_eq!(nbody_1().axis_fingerprint(0), initial);
+
+        nbody.run_step();
+        assert_ne!(nbody.axis_fingerprint(0), initial);
+    }
+
+    #[test]
+    fn finds_axis_cycles() {
+        let nbody = nbody_1();
+        let cycles: Vec<u64> = (0..3).map(|axis| axis_cycle(&nbody.axis(axis))).collect();
+        assert_eq!(cycles, vec![18, 28, 44]);
+    }
+
+    #[test]
+    fn finds_repeated_states() {
+        assert_eq!(solve_part2(TEST_INPUT1).unwrap(), 2772);
+        assert_eq!(solve_part2(TEST_INPUT2).unwrap(), 4686774924);
+    }
+
+    /// Each step changes a velocity component by at most one per other body, so after `steps`
+    /// steps no speed or displacement can exceed what constant maximum acceleration would give.
+    fn energy_bound(initial: &NBody, steps: i64) -> i64 {
+        let body_count = initial.bodies.len() as i64;
+        let max_speed = (body_count - 1) * steps;
+        let max_initial_position = initial
+            .bodies
+            .iter()
+            .map(|body| {
+                body.position
+                    .x
+                    .abs()
+                    .max(body.position.y.abs())
+                    .max(body.position.z.abs())
+            })
+            .max()
+            .unwrap_or(0);
+        let max_position = max_initial_position + (body_count - 1) * steps * (steps + 1) / 2;
+        body_count * (3 * max_position) * (3 * max_speed)
+    }
+
+    prop_compose! {
+        fn arb_body()(x in -100i64..=100, y in -100i64..=100, z in -100i64..=100) -> Body {
+            Body {
+                position: Vector { x, y, z },
+                velocity: Vector::default(),
+            }
+        }
+    }
+
+    prop_compose! {
+        fn arb_nbody()(bodies in vec(arb_body(), 1..=8)) -> NBody {
+            NBody { bodies }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn conserves_momentum_from_rest(initial in arb_nbody()) {
+            let mut nbody = initial.clone();
+            for step in 1..=100 {
+                nbody.run_step();
+                prop_assert_eq!(nbody.total_momentum(), Vector::default());
+                prop_assert!(nbody.total_energy() <= energy_bound(&initial, step));
+            }
+        }
+    }
+}
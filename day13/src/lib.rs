@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::result;
+
+use aoc_utils::{read_string, BoundingBox, Point2D};
+use intcode::{narrow_u8, Intcode, StopReason};
+use num_enum::TryFromPrimitive;
+
+pub const INPUT: &str = "input/input.txt";
+
+pub type Result<T> = result::Result<T, Box<dyn Error>>;
+
+#[derive(Debug, PartialEq, TryFromPrimitive)]
+#[repr(u8)]
+enum Tile {
+    Empty = 0,
+    Wall = 1,
+    Block = 2,
+    HorizontalPaddle = 3,
+    Ball = 4,
+}
+
+#[derive(Debug)]
+pub struct Game {
+    intcode: Intcode,
+    tiles: HashMap<Point2D<i64>, Tile>,
+    ball: Option<Point2D<i64>>,
+    paddle: Option<Point2D<i64>>,
+    score: i64,
+    steps: usize,
+    /// `(step, score)` for every step that changed the score.
+    score_history: Vec<(usize, i64)>,
+}
+
+impl Game {
+    pub fn new(intcode: Intcode) -> Game {
+        Game {
+            intcode,
+            tiles: HashMap::new(),
+            ball: None,
+            paddle: None,
+            score: 0,
+            steps: 0,
+            score_history: vec![],
+        }
+    }
+
+    pub fn score(&self) -> i64 {
+        self.score
+    }
+
+    /// Clears everything the game has seen so it can be replayed with `intcode`.
+    pub fn reset(&mut self, intcode: Intcode) {
+        *self = Game::new(intcode);
+    }
+
+    fn update(&mut self, output: Vec<i64>) -> Result<()> {
+        if !output.len().is_multiple_of(3) {
+            return Err(From::from(format!(
+                "Expected output in triples, got {} values: {:?}",
+                output.len(),
+                output
+            )));
+        }
+        for index in (0..output.len()).step_by(3) {
+            if output[index] == -1 {
+                if output[index + 2] != self.score {
+                    self.score_history.push((self.steps, output[index + 2]));
+                }
+                self.score = output[index + 2];
+            } else {
+                let x = output[index];
+                let y = output[index + 1];
+                let coord = Point2D { x, y };
+                let tile = Tile::try_from(narrow_u8(output[index + 2])?)
+                    .map_err(|_| format!("Unknown tile {} at {}", output[index + 2], coord))?;
+
+                if tile == Tile::Ball {
+                    self.ball = Some(coord);
+                } else if tile == Tile::HorizontalPaddle {
+                    self.paddle = Some(coord);
+                }
+
+                self.tiles.insert(Point2D { x, y }, tile);
+            }
+        }
+        Ok(())
+    }
+
+    fn step(&mut self, input: Option<i64>) -> Result<()> {
+        self.steps += 1;
+        let inputs: Vec<i64> = input.into_iter().collect();
+        let (output, stop_reason) = self.intcode.run(&inputs, None)?;
+        self.update(output)?;
+        if input.is_none() && stop_reason == StopReason::NeedInput {
+            return Err(From::from("program requested input but none available"));
+        }
+        Ok(())
+    }
+
+    /// Plays until the game ends, keeping the paddle under the ball.
+    pub fn play(&mut self) -> Result<()> {
+        let mut input;
+        while !self.intcode.halted {
+            input = 0;
+            if let Some(ball_coord) = self.ball {
+                if let Some(paddle_coord) = self.paddle {
+                    if ball_coord.x > paddle_coord.x {
+                        input = 1;
+                    } else if ball_coord.x < paddle_coord.x {
+                        input = -1;
+                    }
+                }
+            }
+
+            self.step(Some(input))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Score: {}", self.score)?;
+        let bounds = BoundingBox::from_points(self.tiles.keys().copied()).unwrap_or_default();
+        for y in bounds.min().y..=bounds.max().y {
+            let mut row_string = String::new();
+            for x in bounds.min().x..=bounds.max().x {
+                row_string += match self.tiles.get(&Point2D { x, y }).unwrap_or(&Tile::Empty) {
+                    Tile::Empty => " ",
+                    Tile::Wall => "|",
+                    Tile::Block => "#",
+                    Tile::HorizontalPaddle => "=",
+                    Tile::Ball => "o",
+                };
+            }
+            writeln!(f, "{}", row_string)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn solve_part1_from_str(input: &str) -> Result<i64> {
+    let mut game = Game::new(intcode::parse(input)?);
+    game.step(Some(0))?;
+    Ok(game.tiles.values().fold(0, |acc, tile| {
+        if *tile == Tile::Block {
+            return acc + 1;
+        }
+        acc
+    }))
+}
+
+pub fn solve_part2_from_str(input: &str) -> Result<i64> {
+    let mut game = Game::new(intcode::parse(input)?);
+    game.play()?;
+    Ok(game.score())
+}
+
+pub fn solve_part1() -> Result<i64> {
+    solve_part1_from_str(&read_string(INPUT)?)
+}
+
+pub fn solve_part2() -> Result<i64> {
+    solve_part2_from_str(&read_string(INPUT)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::assert_grid_eq;
+
+    #[test]
+    fn displays_game() {
+        let mut game = Game::new(
+            "104,1,104,0,104,1,104,3,104,0,104,2,104,2,104,1,104,4,104,-1,104,0,104,7,99"
+                .parse()
+                .unwrap(),
+        );
+        game.step(None).unwrap();
+        assert_grid_eq(&game.to_string(), "Score: 7\n| #\n o\n");
+    }
+
+    #[test]
+    fn builds_game_from_string_program() {
+        // two blocks and a wall, then the ball and paddle on the row below
+        let program = "104,0,104,0,104,2,104,1,104,0,104,2,104,2,104,0,104,1,\
+                       104,0,104,1,104,4,104,2,104,1,104,3,99";
+        let mut game = Game::new(intcode::parse(program).unwrap());
+        game.step(None).unwrap();
+        assert_grid_eq(&game.to_string(), "Score: 0\n##|\no =\n");
+        assert_eq!(game.ball, Some(Point2D::new(0, 1)));
+        assert_eq!(game.paddle, Some(Point2D::new(2, 1)));
+        assert_eq!(solve_part1_from_str(program).unwrap(), 2);
+    }
+
+    #[test]
+    fn resets_game() {
+        let program = "104,1,104,0,104,4,104,0,104,0,104,3,104,-1,104,0,104,5,3,100,99";
+        let mut game = Game::new(intcode::parse(program).unwrap());
+        game.play().unwrap();
+        assert_eq!(game.score, 5);
+        assert!(game.intcode.halted);
+
+        game.reset(intcode::parse(program).unwrap());
+        assert!(game.tiles.is_empty());
+        assert_eq!((game.ball, game.paddle), (None, None));
+        assert_eq!(game.score, 0);
+        assert_eq!(game.steps, 0);
+        assert!(game.score_history.is_empty());
+        assert!(!game.intcode.halted);
+
+        game.play().unwrap();
+        assert_eq!(game.score, 5);
+        assert_eq!(game.score_history, vec![(1, 5)]);
+    }
+
+    #[test]
+    fn errors_when_input_is_requested_but_unavailable() {
+        let mut game = Game::new("104,1,104,2,104,3,3,0,99".parse().unwrap());
+        assert_eq!(
+            game.step(None).unwrap_err().to_string(),
+            "program requested input but none available"
+        );
+        assert_eq!(game.tiles.len(), 1);
+        assert!(game.step(Some(0)).is_ok());
+        assert!(game.intcode.halted);
+    }
+
+    #[test]
+    fn tracks_score_over_time() {
+        let mut game = Game::new(
+            "104,1,104,0,104,4,104,0,104,0,104,3,104,-1,104,0,104,5,3,100,3,100,\
+             104,-1,104,0,104,12,3,100,104,-1,104,0,104,12,99"
+                .parse()
+                .unwrap(),
+        );
+        game.play().unwrap();
+        assert_eq!(game.score, 12);
+        assert_eq!(game.score_history, vec![(1, 5), (2, 12)]);
+    }
+
+    #[test]
+    fn reports_unknown_tiles() {
+        let mut game = Game::new("99".parse().unwrap());
+        assert_eq!(
+            game.update(vec![3, 4, 7]).unwrap_err().to_string(),
+            "Unknown tile 7 at (3, 4)"
+        );
+    }
+
+    #[test]
+    fn rejects_misaligned_output() {
+        let mut game = Game::new("99".parse().unwrap());
+        assert_eq!(
+            game.update(vec![3, 4]).unwrap_err().to_string(),
+            "Expected output in triples, got 2 values: [3, 4]"
+        );
+        assert!(game.tiles.is_empty());
+    }
+
+    #[test]
+    fn rejects_tiles_outside_u8() {
+        let mut game = Game::new("99".parse().unwrap());
+        assert_eq!(
+            game.update(vec![3, 4, 256]).unwrap_err().to_string(),
+            "Output 256 does not fit in a u8"
+        );
+        assert_eq!(
+            game.update(vec![3, 4, -1]).unwrap_err().to_string(),
+            "Output -1 does not fit in a u8"
+        );
+    }
+}
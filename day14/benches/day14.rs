@@ -0,0 +1,31 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use day14::{calculate_max_fuel_from_ore, read_reactions, solve_part1, INPUT, ORE_BUDGET};
+
+const TEST_INPUTS: [&str; 5] = [
+    "input/test1.txt",
+    "input/test2.txt",
+    "input/test3.txt",
+    "input/test4.txt",
+    "input/test5.txt",
+];
+
+fn bench_solve_part1(c: &mut Criterion) {
+    for input in TEST_INPUTS.iter().chain([INPUT].iter()) {
+        c.bench_function(&format!("solve_part1 {}", input), |b| {
+            b.iter(|| solve_part1(black_box(input)).unwrap())
+        });
+    }
+}
+
+fn bench_max_fuel_from_ore(c: &mut Criterion) {
+    for input in TEST_INPUTS[2..].iter() {
+        let reactions = read_reactions(input).unwrap();
+        c.bench_function(&format!("calculate_max_fuel_from_ore {}", input), |b| {
+            b.iter(|| calculate_max_fuel_from_ore(black_box(ORE_BUDGET), &reactions).unwrap())
+        });
+    }
+}
+
+criterion_group!(benches, bench_solve_part1, bench_max_fuel_from_ore);
+criterion_main!(benches);
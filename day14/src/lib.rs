@@ -0,0 +1,770 @@
+#[macro_use]
+extern crate lazy_static;
+
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{prelude::*, BufReader};
+use std::result;
+use std::str::FromStr;
+
+use petgraph::algo::{is_cyclic_directed, toposort};
+use petgraph::graph::{DiGraph, NodeIndex};
+use regex::Regex;
+
+pub type Result<T> = result::Result<T, Box<dyn Error>>;
+
+pub const INPUT: &str = "input/input.txt";
+pub const ORE_BUDGET: u64 = 1_000_000_000_000;
+
+#[derive(Debug, PartialEq)]
+pub struct Reactions {
+    reactions: HashMap<String, Reaction>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Reaction {
+    output: ChemicalAmount,
+    inputs: Vec<ChemicalAmount>,
+}
+
+#[derive(Debug, Hash, PartialEq, Eq)]
+struct ChemicalAmount {
+    chemical: String,
+    amount: u64,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ReactionsError {
+    CyclicDependency { chemical: String },
+    MissingReaction { chemical: String },
+    OreProduced,
+    ZeroAmount { chemical: String },
+    NoInputs { chemical: String },
+}
+
+impl fmt::Display for ReactionsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReactionsError::CyclicDependency { chemical } => {
+                write!(f, "Reactions contain a cyclic dependency on {}", chemical)
+            }
+            ReactionsError::MissingReaction { chemical } => {
+                write!(f, "No reaction produces {}", chemical)
+            }
+            ReactionsError::OreProduced => {
+                write!(f, "ORE is a raw material, but a reaction produces it")
+            }
+            ReactionsError::ZeroAmount { chemical } => {
+                write!(f, "A reaction uses or produces 0 {}", chemical)
+            }
+            ReactionsError::NoInputs { chemical } => {
+                write!(f, "The reaction producing {} has no inputs", chemical)
+            }
+        }
+    }
+}
+
+impl Error for ReactionsError {}
+
+impl ChemicalAmount {
+    fn new(chemical: impl Into<String>, amount: u64) -> ChemicalAmount {
+        ChemicalAmount {
+            chemical: chemical.into(),
+            amount,
+        }
+    }
+
+    fn chemical(&self) -> &str {
+        &self.chemical
+    }
+
+    fn amount(&self) -> u64 {
+        self.amount
+    }
+}
+
+impl FromStr for Reaction {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Reaction> {
+        lazy_static! {
+            static ref SOURCE_CHEMICALS: Regex =
+                Regex::new(r"(?P<input_amount>\d+) (?P<input_chemical>\w+),? ").unwrap();
+            static ref OUTPUT_CHEMICAL: Regex =
+                Regex::new(r"=> (?P<output_amount>\d+) (?P<output_chemical>\w+)").unwrap();
+        }
+
+        let mut inputs: Vec<ChemicalAmount> = vec![];
+        for captures in SOURCE_CHEMICALS.captures_iter(s) {
+            inputs.push(ChemicalAmount::new(
+                &captures["input_chemical"],
+                captures["input_amount"].parse()?,
+            ));
+        }
+        match OUTPUT_CHEMICAL.captures(s) {
+            None => Err(From::from(
+                "Malformed reactions, no output chemical could be found",
+            )),
+            Some(captures) => {
+                let output = ChemicalAmount::new(
+                    &captures["output_chemical"],
+                    captures["output_amount"].parse()?,
+                );
+                Ok(Reaction { inputs, output })
+            }
+        }
+    }
+}
+
+impl FromStr for Reactions {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Reactions> {
+        let mut reactions = HashMap::new();
+        for line in s.trim().split('\n') {
+            let reaction: Reaction = line.parse()?;
+            reactions.insert(reaction.output.chemical().to_string(), reaction);
+        }
+
+        Reactions::checked(reactions)
+    }
+}
+
+impl Reactions {
+    fn checked(reactions: HashMap<String, Reaction>) -> Result<Reactions> {
+        let reactions = Reactions { reactions };
+        reactions.validate()?;
+        if let Some(chemical) = reactions.find_cycle() {
+            return Err(Box::new(ReactionsError::CyclicDependency { chemical }));
+        }
+        Ok(reactions)
+    }
+
+    /// Parses one reaction per line as they are read, skipping blank lines.
+    pub fn from_reader(reader: impl BufRead) -> Result<Reactions> {
+        let mut reactions = HashMap::new();
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let reaction: Reaction = line.parse()?;
+            reactions.insert(reaction.output.chemical().to_string(), reaction);
+        }
+
+        Reactions::checked(reactions)
+    }
+
+    pub fn all_chemicals(&self) -> HashSet<&str> {
+        let mut chemicals = HashSet::new();
+        for reaction in self.reactions.values() {
+            chemicals.insert(reaction.output.chemical());
+            for input in reaction.inputs.iter() {
+                chemicals.insert(input.chemical());
+            }
+        }
+        chemicals
+    }
+
+    /// Chemicals that are both produced and consumed, i.e. everything but ORE and FUEL.
+    pub fn intermediate_chemicals(&self) -> HashSet<&str> {
+        let consumed: HashSet<&str> = self
+            .reactions
+            .values()
+            .flat_map(|reaction| reaction.inputs.iter())
+            .map(|input| input.chemical())
+            .collect();
+        self.reactions
+            .keys()
+            .map(|chemical| chemical.as_str())
+            .filter(|chemical| consumed.contains(chemical))
+            .collect()
+    }
+
+    /// ORE needed to produce `amount` FUEL in one batch, sharing left overs across the whole
+    /// calculation. Saturates at `u64::MAX`.
+    pub fn ore_per_fuel_accumulate(&self, amount: u64) -> u64 {
+        self.ore_for("FUEL", amount, &mut HashMap::new())
+    }
+
+    /// Extra ORE needed to make one more FUEL after `around_fuel`, which varies with how much
+    /// is left over at that point. `None` if `around_fuel` is already `u64::MAX`.
+    pub fn marginal_ore(&self, around_fuel: u64) -> Option<u64> {
+        Some(
+            self.ore_per_fuel_accumulate(around_fuel.checked_add(1)?)
+                .saturating_sub(self.ore_per_fuel_accumulate(around_fuel)),
+        )
+    }
+
+    pub fn ore_for_one_fuel(&self) -> u64 {
+        self.ore_for("FUEL", 1, &mut HashMap::new())
+    }
+
+    /// ORE needed to produce `amount` of `chemical`, using up and adding to `left_overs`.
+    /// Saturates at `u64::MAX`.
+    pub fn ore_for(
+        &self,
+        chemical: &str,
+        amount: u64,
+        left_overs: &mut HashMap<String, u64>,
+    ) -> u64 {
+        let mut wide_left_overs: HashMap<String, u128> = left_overs
+            .iter()
+            .map(|(chemical, left_over)| (chemical.clone(), u128::from(*left_over)))
+            .collect();
+        let ore = self.wide_ore_for(chemical, u128::from(amount), &mut wide_left_overs);
+        for (chemical, left_over) in wide_left_overs {
+            // never more than one batch is left over, so this always fits back into a u64
+            left_overs.insert(chemical, left_over as u64);
+        }
+        u64::try_from(ore).unwrap_or(u64::MAX)
+    }
+
+    // amounts are tracked as u128 internally since batch sizes multiplied by large FUEL counts
+    // can overflow u64 even when the final ORE count does not
+    //
+    // chemicals are visited in topological order so that the total needed amount of a chemical
+    // shared by several reactions is known before any of it is produced
+    fn wide_ore_for(
+        &self,
+        chemical: &str,
+        amount: u128,
+        left_overs: &mut HashMap<String, u128>,
+    ) -> u128 {
+        let mut needed: HashMap<String, u128> = HashMap::new();
+        needed.insert(chemical.to_string(), amount);
+
+        for chemical in self.topological_sort() {
+            let reaction = match self.reactions.get(&chemical) {
+                Some(reaction) => reaction,
+                None => continue,
+            };
+            let mut needed_amount = *needed.get(&chemical).unwrap_or(&0);
+            let left_over = left_overs.entry(chemical).or_insert(0);
+            if *left_over >= needed_amount {
+                *left_over -= needed_amount;
+                continue;
+            }
+            needed_amount -= *left_over;
+
+            let output_amount = u128::from(reaction.output.amount());
+            let production_count = needed_amount.div_ceil(output_amount);
+            *left_over = (output_amount * production_count) - needed_amount;
+            for input in reaction.inputs.iter() {
+                *needed.entry(input.chemical().to_string()).or_insert(0) +=
+                    u128::from(input.amount()) * production_count;
+            }
+        }
+
+        *needed.get("ORE").unwrap_or(&0)
+    }
+
+    /// Checks that every input other than ORE is the output of some reaction and that every
+    /// reaction uses a non-zero amount of something, so all FUEL costs some ORE.
+    fn validate(&self) -> result::Result<(), ReactionsError> {
+        if !self.reactions.contains_key("FUEL") {
+            return Err(ReactionsError::MissingReaction {
+                chemical: "FUEL".to_string(),
+            });
+        }
+        if self.reactions.contains_key("ORE") {
+            return Err(ReactionsError::OreProduced);
+        }
+        for reaction in self.reactions.values() {
+            if reaction.inputs.is_empty() {
+                return Err(ReactionsError::NoInputs {
+                    chemical: reaction.output.chemical().to_string(),
+                });
+            }
+            if let Some(zero) = reaction
+                .inputs
+                .iter()
+                .chain(Some(&reaction.output))
+                .find(|chemical_amount| chemical_amount.amount() == 0)
+            {
+                return Err(ReactionsError::ZeroAmount {
+                    chemical: zero.chemical().to_string(),
+                });
+            }
+            for input in reaction.inputs.iter() {
+                if input.chemical() != "ORE" && !self.reactions.contains_key(input.chemical()) {
+                    return Err(ReactionsError::MissingReaction {
+                        chemical: input.chemical().to_string(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Graph with an edge from each reaction's output to each of its inputs, weighted by the
+    /// amount of input required.
+    pub fn reaction_graph(&self) -> DiGraph<String, u64> {
+        let mut graph = DiGraph::new();
+        let mut nodes: HashMap<&str, NodeIndex> = HashMap::new();
+        for chemical in self.all_chemicals() {
+            nodes.insert(chemical, graph.add_node(chemical.to_string()));
+        }
+        for reaction in self.reactions.values() {
+            for input in reaction.inputs.iter() {
+                graph.add_edge(
+                    nodes[reaction.output.chemical()],
+                    nodes[input.chemical()],
+                    input.amount(),
+                );
+            }
+        }
+        graph
+    }
+
+    fn find_cycle(&self) -> Option<String> {
+        let graph = self.reaction_graph();
+        if !is_cyclic_directed(&graph) {
+            return None;
+        }
+        toposort(&graph, None)
+            .err()
+            .map(|cycle| graph[cycle.node_id()].clone())
+    }
+
+    /// Orders chemicals so that every chemical comes before all of its inputs (FUEL first, ORE
+    /// last).
+    pub fn topological_sort(&self) -> Vec<String> {
+        let graph = self.reaction_graph();
+        toposort(&graph, None)
+            .expect("Reactions contain a cyclic dependency")
+            .into_iter()
+            .map(|node| graph[node].clone())
+            .collect()
+    }
+}
+
+fn calculate_wide_ore_for_n_fuel(reactions: &Reactions, fuel: u128) -> u128 {
+    reactions.wide_ore_for("FUEL", fuel, &mut HashMap::new())
+}
+
+pub fn calculate_ore_for_n_fuel(reactions: &Reactions, fuel: u64) -> Result<u64> {
+    let ore = calculate_wide_ore_for_n_fuel(reactions, u128::from(fuel));
+    u64::try_from(ore).map_err(|_| From::from(format!("{} ORE does not fit in a u64", ore)))
+}
+
+/// Binary searches for the most FUEL that can be produced without spending more than `ore_budget`.
+pub fn calculate_max_fuel_from_ore(ore_budget: u64, reactions: &Reactions) -> Result<u64> {
+    let ore_budget = u128::from(ore_budget);
+    let ore_for_one_fuel = calculate_wide_ore_for_n_fuel(reactions, 1);
+    if ore_for_one_fuel > ore_budget {
+        return Ok(0);
+    }
+
+    // left overs make each additional FUEL cost at most as much as the first one
+    let mut lower = ore_budget / ore_for_one_fuel;
+    let mut upper = lower * 2;
+    while calculate_wide_ore_for_n_fuel(reactions, upper) <= ore_budget {
+        lower = upper;
+        upper *= 2;
+    }
+
+    while lower + 1 < upper {
+        let middle = lower + (upper - lower) / 2;
+        if calculate_wide_ore_for_n_fuel(reactions, middle) <= ore_budget {
+            lower = middle;
+        } else {
+            upper = middle;
+        }
+    }
+    u64::try_from(lower).map_err(|_| From::from(format!("{} FUEL does not fit in a u64", lower)))
+}
+
+pub fn parse_reactions(s: &str) -> Result<Reactions> {
+    s.parse()
+}
+
+pub fn read_reactions(filename: &str) -> Result<Reactions> {
+    Reactions::from_reader(BufReader::new(File::open(filename)?))
+}
+
+pub fn solve_part1_from_str(input: &str) -> Result<u64> {
+    let reactions = parse_reactions(input)?;
+    Ok(reactions.ore_for_one_fuel())
+}
+
+pub fn solve_part2_from_str(input: &str) -> Result<u64> {
+    let reactions = parse_reactions(input)?;
+    calculate_max_fuel_from_ore(ORE_BUDGET, &reactions)
+}
+
+pub fn solve_part1(filename: &str) -> Result<u64> {
+    let reactions = read_reactions(filename)?;
+    Ok(reactions.ore_for_one_fuel())
+}
+
+pub fn solve_part2(filename: &str) -> Result<u64> {
+    let reactions = read_reactions(filename)?;
+    calculate_max_fuel_from_ore(ORE_BUDGET, &reactions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    const TEST_INPUT1: &str = "input/test1.txt";
+    const TEST_INPUT2: &str = "input/test2.txt";
+    const TEST_INPUT3: &str = "input/test3.txt";
+    const TEST_INPUT4: &str = "input/test4.txt";
+    const TEST_INPUT5: &str = "input/test5.txt";
+
+    fn reactions_1() -> Reactions {
+        Reactions {
+            reactions: vec![
+                Reaction {
+                    output: ChemicalAmount::new("E", 1),
+                    inputs: vec![ChemicalAmount::new("A", 7), ChemicalAmount::new("D", 1)],
+                },
+                Reaction {
+                    output: ChemicalAmount::new("A", 10),
+                    inputs: vec![ChemicalAmount::new("ORE", 10)],
+                },
+                Reaction {
+                    output: ChemicalAmount::new("D", 1),
+                    inputs: vec![ChemicalAmount::new("A", 7), ChemicalAmount::new("C", 1)],
+                },
+                Reaction {
+                    output: ChemicalAmount::new("FUEL", 1),
+                    inputs: vec![ChemicalAmount::new("A", 7), ChemicalAmount::new("E", 1)],
+                },
+                Reaction {
+                    output: ChemicalAmount::new("B", 1),
+                    inputs: vec![ChemicalAmount::new("ORE", 1)],
+                },
+                Reaction {
+                    output: ChemicalAmount::new("C", 1),
+                    inputs: vec![ChemicalAmount::new("A", 7), ChemicalAmount::new("B", 1)],
+                },
+            ]
+            .into_iter()
+            .map(|reaction| (reaction.output.chemical().to_string(), reaction))
+            .collect(),
+        }
+    }
+
+    #[test]
+    fn reads_reactions() {
+        assert_eq!(read_reactions(TEST_INPUT1).unwrap(), reactions_1());
+    }
+
+    #[test]
+    fn reads_reactions_from_reader() {
+        let input = include_str!("../input/test1.txt");
+        assert_eq!(
+            Reactions::from_reader(Cursor::new(input.as_bytes())).unwrap(),
+            read_reactions(TEST_INPUT1).unwrap()
+        );
+        assert_eq!(
+            Reactions::from_reader(Cursor::new(&b"\n10 ORE => 1 A\n\n1 A => 1 FUEL\n"[..]))
+                .unwrap(),
+            parse_reactions("10 ORE => 1 A\n1 A => 1 FUEL").unwrap()
+        );
+    }
+
+    #[test]
+    fn parses_reactions() {
+        assert_eq!(
+            parse_reactions(include_str!("../input/test1.txt")).unwrap(),
+            reactions_1()
+        );
+    }
+
+    #[test]
+    fn lists_chemicals() {
+        let reactions = reactions_1();
+        assert_eq!(
+            reactions.all_chemicals(),
+            vec!["ORE", "A", "B", "C", "D", "E", "FUEL"]
+                .into_iter()
+                .collect()
+        );
+        assert_eq!(
+            reactions.intermediate_chemicals(),
+            vec!["A", "B", "C", "D", "E"].into_iter().collect()
+        );
+    }
+
+    #[test]
+    fn rejects_cyclic_reactions() {
+        let error = parse_reactions("1 A => 1 FUEL\n1 FUEL => 1 A").unwrap_err();
+        match error.downcast_ref::<ReactionsError>() {
+            Some(ReactionsError::CyclicDependency { chemical }) => {
+                assert!(chemical == "A" || chemical == "FUEL")
+            }
+            other => panic!("expected a cyclic dependency error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_reactions_with_missing_inputs() {
+        let error = parse_reactions("10 ORE => 1 A\n1 A, 2 UNOBTANIUM => 1 FUEL").unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<ReactionsError>(),
+            Some(&ReactionsError::MissingReaction {
+                chemical: "UNOBTANIUM".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_reactions_without_fuel() {
+        let error = parse_reactions("10 ORE => 1 A\n1 A => 1 B").unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<ReactionsError>(),
+            Some(&ReactionsError::MissingReaction {
+                chemical: "FUEL".to_string()
+            })
+        );
+        assert_eq!(error.to_string(), "No reaction produces FUEL");
+    }
+
+    #[test]
+    fn rejects_reactions_producing_ore() {
+        let error = parse_reactions("10 ORE => 1 A\n1 A => 2 ORE\n1 A => 1 FUEL").unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<ReactionsError>(),
+            Some(&ReactionsError::OreProduced)
+        );
+        assert_eq!(
+            error.to_string(),
+            "ORE is a raw material, but a reaction produces it"
+        );
+    }
+
+    #[test]
+    fn rejects_zero_amounts() {
+        let error = parse_reactions("1 ORE => 0 FUEL").unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<ReactionsError>(),
+            Some(&ReactionsError::ZeroAmount {
+                chemical: "FUEL".to_string()
+            })
+        );
+        assert_eq!(error.to_string(), "A reaction uses or produces 0 FUEL");
+
+        let error = parse_reactions("10 ORE => 1 A\n0 A, 1 ORE => 1 FUEL").unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<ReactionsError>(),
+            Some(&ReactionsError::ZeroAmount {
+                chemical: "A".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_reactions_without_inputs() {
+        let error = parse_reactions("=> 1 FUEL").unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<ReactionsError>(),
+            Some(&ReactionsError::NoInputs {
+                chemical: "FUEL".to_string()
+            })
+        );
+        assert_eq!(
+            error.to_string(),
+            "The reaction producing FUEL has no inputs"
+        );
+        assert!(solve_part2_from_str("=> 1 A\n1 A => 1 FUEL").is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_reactions() {
+        let error = |s: &str| parse_reactions(s).unwrap_err().to_string();
+        assert_eq!(
+            error("99999999999999999999 ORE => 1 FUEL"),
+            "number too large to fit in target type"
+        );
+        assert_eq!(
+            error("10 ORE => 99999999999999999999 FUEL"),
+            "number too large to fit in target type"
+        );
+        assert_eq!(
+            error("10 ORE, 1 A"),
+            "Malformed reactions, no output chemical could be found"
+        );
+        assert_eq!(
+            error(""),
+            "Malformed reactions, no output chemical could be found"
+        );
+    }
+
+    #[test]
+    fn sorts_reactions_topologically() {
+        let reactions = reactions_1();
+        let sorted = reactions.topological_sort();
+        assert_eq!(sorted.len(), 7);
+        assert_eq!(sorted.first().unwrap(), "FUEL");
+        assert_eq!(sorted.last().unwrap(), "ORE");
+
+        let position = |chemical: &str| sorted.iter().position(|c| c == chemical).unwrap();
+        for reaction in reactions.reactions.values() {
+            for input in reaction.inputs.iter() {
+                assert!(position(input.chemical()) > position(reaction.output.chemical()));
+            }
+        }
+    }
+
+    #[test]
+    fn calculates_ore_for_one_fuel() {
+        let reactions = reactions_1();
+        assert_eq!(reactions.ore_for_one_fuel(), 31);
+        assert_eq!(reactions.ore_for("FUEL", 1, &mut HashMap::new()), 31);
+
+        let mut left_overs = HashMap::new();
+        assert_eq!(reactions.ore_for("C", 1, &mut left_overs), 11);
+        assert_eq!(left_overs["A"], 3);
+        assert_eq!(reactions.ore_for("FUEL", 1, &mut left_overs), 31);
+        assert_eq!(left_overs["A"], 5);
+    }
+
+    #[test]
+    fn builds_reaction_graph() {
+        let graph = reactions_1().reaction_graph();
+        assert_eq!(graph.node_count(), 7);
+        assert_eq!(graph.edge_count(), 10);
+        assert_eq!(
+            graph
+                .raw_edges()
+                .iter()
+                .map(|edge| edge.weight)
+                .sum::<u64>(),
+            43
+        );
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(solve_part1(TEST_INPUT1).unwrap(), 31);
+        assert_eq!(solve_part1(TEST_INPUT2).unwrap(), 165);
+        assert_eq!(solve_part1(TEST_INPUT3).unwrap(), 13312);
+        assert_eq!(solve_part1(TEST_INPUT4).unwrap(), 180697);
+        assert_eq!(solve_part1(TEST_INPUT5).unwrap(), 2210736);
+    }
+
+    #[test]
+    fn calculates_max_fuel_from_ore() {
+        for (input, fuel) in [
+            (include_str!("../input/test3.txt"), 82892753),
+            (include_str!("../input/test4.txt"), 5586022),
+            (include_str!("../input/test5.txt"), 460664),
+        ]
+        .iter()
+        {
+            let reactions = parse_reactions(input).unwrap();
+            assert_eq!(
+                calculate_max_fuel_from_ore(ORE_BUDGET, &reactions).unwrap(),
+                *fuel
+            );
+        }
+    }
+
+    #[test]
+    fn calculates_no_fuel_when_over_budget() {
+        let reactions = parse_reactions(include_str!("../input/test1.txt")).unwrap();
+        assert_eq!(calculate_max_fuel_from_ore(30, &reactions).unwrap(), 0);
+        assert_eq!(calculate_max_fuel_from_ore(31, &reactions).unwrap(), 1);
+    }
+
+    #[test]
+    fn accounts_beyond_u64() {
+        let reactions = parse_reactions(concat!(
+            "3 ORE => 10000000000 A\n",
+            "10000000000 A => 1 B\n",
+            "10000000000 B => 1 FUEL"
+        ))
+        .unwrap();
+        assert_eq!(
+            calculate_ore_for_n_fuel(&reactions, 1).unwrap(),
+            30_000_000_000
+        );
+
+        let reactions = parse_reactions("10000000000 ORE => 1 A\n10000000000 A => 1 FUEL").unwrap();
+        assert!(calculate_ore_for_n_fuel(&reactions, 1).is_err());
+    }
+
+    #[test]
+    fn accumulates_ore_per_fuel() {
+        for input in [
+            TEST_INPUT1,
+            TEST_INPUT2,
+            TEST_INPUT3,
+            TEST_INPUT4,
+            TEST_INPUT5,
+        ]
+        .iter()
+        {
+            let reactions = read_reactions(input).unwrap();
+            assert_eq!(
+                reactions.ore_per_fuel_accumulate(1),
+                solve_part1(input).unwrap()
+            );
+        }
+
+        let reactions = read_reactions(TEST_INPUT3).unwrap();
+        assert_eq!(
+            reactions.ore_per_fuel_accumulate(82892753),
+            calculate_ore_for_n_fuel(&reactions, 82892753).unwrap()
+        );
+        assert!(reactions.ore_per_fuel_accumulate(82892753) <= ORE_BUDGET);
+    }
+
+    #[test]
+    fn calculates_marginal_ore() {
+        let reactions = read_reactions(TEST_INPUT3).unwrap();
+        for fuel in [0, 1, 10, 1000, 82892753].iter() {
+            assert_eq!(
+                reactions.marginal_ore(*fuel).unwrap(),
+                calculate_ore_for_n_fuel(&reactions, fuel + 1).unwrap()
+                    - calculate_ore_for_n_fuel(&reactions, *fuel).unwrap()
+            );
+        }
+        assert_eq!(reactions.marginal_ore(0), Some(13312));
+        assert!(reactions.marginal_ore(1).unwrap() < 13312);
+        assert_eq!(reactions.marginal_ore(u64::MAX), None);
+    }
+
+    #[test]
+    fn calculates_ore_for_diamond_dependencies() {
+        let reactions = parse_reactions(concat!(
+            "10 ORE => 10 A\n",
+            "3 ORE => 2 B\n",
+            "3 A, 1 B => 1 C\n",
+            "4 A, 1 B => 1 D\n",
+            "1 C, 1 D => 1 FUEL"
+        ))
+        .unwrap();
+        assert_eq!(reactions.ore_for_one_fuel(), 13);
+
+        let mut left_overs = HashMap::new();
+        assert_eq!(reactions.ore_for("FUEL", 2, &mut left_overs), 26);
+        assert_eq!(left_overs["A"], 6);
+        assert_eq!(left_overs["B"], 0);
+
+        assert_eq!(reactions.ore_for("A", 6, &mut left_overs), 0);
+        assert_eq!(reactions.ore_for("A", 1, &mut left_overs), 10);
+        assert_eq!(left_overs["A"], 9);
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(solve_part2(TEST_INPUT3).unwrap(), 82892753);
+    }
+
+    #[test]
+    fn calculates_ore_beyond_u32() {
+        let reactions = parse_reactions("5000000000 ORE => 1 A\n3 A => 1 FUEL").unwrap();
+        assert_eq!(
+            calculate_ore_for_n_fuel(&reactions, 1).unwrap(),
+            15_000_000_000
+        );
+    }
+}
@@ -0,0 +1,293 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::result;
+
+use aoc_utils::{read_string, BoundingBox, Direction4, Grid, Point2D};
+use intcode::{narrow_u8, Intcode, StopReason};
+use num_enum::TryFromPrimitive;
+
+pub const INPUT: &str = "input/input.txt";
+
+pub type Result<T> = result::Result<T, Box<dyn Error>>;
+
+const DIRECTIONS: [Direction4; 4] = [
+    Direction4::North,
+    Direction4::East,
+    Direction4::South,
+    Direction4::West,
+];
+
+#[derive(Debug, PartialEq, Clone, Copy, TryFromPrimitive)]
+#[repr(u8)]
+pub enum Status {
+    HitWall = 0,
+    Moved = 1,
+    FoundOxygen = 2,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Cell {
+    Unknown,
+    Empty,
+    Wall,
+    Oxygen,
+}
+
+/// Anything that can be sent one step in a direction and report what happened.
+pub trait Droid {
+    fn step(&mut self, direction: Direction4) -> Result<Status>;
+}
+
+pub struct RepairDroid {
+    intcode: Intcode,
+}
+
+impl RepairDroid {
+    pub fn new(intcode: Intcode) -> RepairDroid {
+        RepairDroid { intcode }
+    }
+}
+
+impl Droid for RepairDroid {
+    fn step(&mut self, direction: Direction4) -> Result<Status> {
+        let command = match direction {
+            Direction4::North => 1,
+            Direction4::South => 2,
+            Direction4::West => 3,
+            Direction4::East => 4,
+        };
+        let (output, stop_reason) = self.intcode.run(&[command], Some(1))?;
+        if stop_reason != StopReason::OutputLimit {
+            return Err(From::from(
+                "repair droid stopped without reporting a status",
+            ));
+        }
+        Ok(Status::try_from(narrow_u8(output[0])?)?)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ShipMap {
+    grid: Grid<Cell>,
+    /// Where the droid started, in grid coordinates.
+    origin: (usize, usize),
+}
+
+impl ShipMap {
+    /// Maps every cell reachable by `droid`, exploring depth first and backtracking out of each
+    /// dead end, so the droid ends up back where it started.
+    pub fn explore(droid: &mut impl Droid) -> Result<ShipMap> {
+        let origin = Point2D::new(0, 0);
+        let mut cells = HashMap::new();
+        cells.insert(origin, Cell::Empty);
+        explore_from(droid, origin, &mut cells)?;
+
+        let bounds = BoundingBox::from_points(cells.keys().copied()).unwrap_or_default();
+        let mut grid = Grid::new(
+            bounds.width() as usize,
+            bounds.height() as usize,
+            Cell::Unknown,
+        );
+        for (point, cell) in cells.iter() {
+            let offset = *point - bounds.min();
+            grid.set(offset.x as usize, offset.y as usize, *cell);
+        }
+        let origin = origin - bounds.min();
+        Ok(ShipMap {
+            grid,
+            origin: (origin.x as usize, origin.y as usize),
+        })
+    }
+
+    fn oxygen_system(&self) -> Option<(usize, usize)> {
+        self.grid
+            .iter_cells()
+            .find(|(_, _, cell)| **cell == Cell::Oxygen)
+            .map(|(x, y, _)| (x, y))
+    }
+
+    /// Fewest moves from the droid's starting point to the oxygen system.
+    pub fn oxygen_distance(&self) -> Option<usize> {
+        self.grid
+            .shortest_path_distance(self.origin, self.oxygen_system()?, is_open)
+    }
+
+    /// Minutes until oxygen spreading one cell per minute from the oxygen system fills every
+    /// reachable cell.
+    pub fn oxygen_fill_time(&self) -> Option<usize> {
+        let (x, y) = self.oxygen_system()?;
+        Some(self.grid.flood_fill_time(x, y, is_open))
+    }
+}
+
+fn is_open(cell: &Cell) -> bool {
+    *cell == Cell::Empty || *cell == Cell::Oxygen
+}
+
+fn explore_from(
+    droid: &mut impl Droid,
+    position: Point2D<i64>,
+    cells: &mut HashMap<Point2D<i64>, Cell>,
+) -> Result<()> {
+    for direction in DIRECTIONS.iter().copied() {
+        let next = position + Point2D::from(direction.to_delta());
+        if cells.contains_key(&next) {
+            continue;
+        }
+        match droid.step(direction)? {
+            Status::HitWall => {
+                cells.insert(next, Cell::Wall);
+            }
+            status => {
+                let cell = if status == Status::FoundOxygen {
+                    Cell::Oxygen
+                } else {
+                    Cell::Empty
+                };
+                cells.insert(next, cell);
+                explore_from(droid, next, cells)?;
+                if droid.step(direction.opposite())? == Status::HitWall {
+                    return Err(From::from(format!(
+                        "Droid could not backtrack from {}",
+                        next
+                    )));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+impl fmt::Display for ShipMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.grid.height() {
+            let mut row_string = String::new();
+            for x in 0..self.grid.width() {
+                if (x, y) == self.origin {
+                    row_string += "D";
+                    continue;
+                }
+                row_string += match self.grid.get(x, y).unwrap_or(&Cell::Unknown) {
+                    Cell::Unknown => " ",
+                    Cell::Empty => ".",
+                    Cell::Wall => "#",
+                    Cell::Oxygen => "O",
+                };
+            }
+            writeln!(f, "{}", row_string)?;
+        }
+        Ok(())
+    }
+}
+
+fn explore_intcode(input: &str) -> Result<ShipMap> {
+    ShipMap::explore(&mut RepairDroid::new(input.parse()?))
+}
+
+pub fn solve_part1_from_str(input: &str) -> Result<usize> {
+    Ok(explore_intcode(input)?
+        .oxygen_distance()
+        .ok_or("No path to the oxygen system")?)
+}
+
+pub fn solve_part2_from_str(input: &str) -> Result<usize> {
+    Ok(explore_intcode(input)?
+        .oxygen_fill_time()
+        .ok_or("No oxygen system found")?)
+}
+
+pub fn solve_part1() -> Result<usize> {
+    solve_part1_from_str(&read_string(INPUT)?)
+}
+
+pub fn solve_part2() -> Result<usize> {
+    solve_part2_from_str(&read_string(INPUT)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::{assert_grid_eq, parse_grid_chars};
+
+    const MAZE: &str = "\
+#########
+#D..#...#
+#.#.#.#.#
+#.#...#O#
+#########
+";
+
+    /// Walks a maze drawn with `#` walls, starting from `D`, instead of running intcode.
+    struct MazeDroid {
+        maze: Grid<char>,
+        position: Point2D<i64>,
+    }
+
+    impl MazeDroid {
+        fn new(maze: &str) -> MazeDroid {
+            let maze = parse_grid_chars(maze);
+            let (x, y, _) = maze.iter_cells().find(|(_, _, c)| **c == 'D').unwrap();
+            MazeDroid {
+                position: Point2D::new(x as i64, y as i64),
+                maze,
+            }
+        }
+    }
+
+    impl Droid for MazeDroid {
+        fn step(&mut self, direction: Direction4) -> Result<Status> {
+            let next = self.position + Point2D::from(direction.to_delta());
+            let status = match self.maze.get(next.x as usize, next.y as usize) {
+                None | Some('#') => return Ok(Status::HitWall),
+                Some('O') => Status::FoundOxygen,
+                Some(_) => Status::Moved,
+            };
+            self.position = next;
+            Ok(status)
+        }
+    }
+
+    #[test]
+    fn explores_maze() {
+        let mut droid = MazeDroid::new(MAZE);
+        let map = ShipMap::explore(&mut droid).unwrap();
+        assert_eq!(droid.position, Point2D::new(1, 1));
+        assert_grid_eq(
+            &map.to_string(),
+            " ### ### \n\
+             #D..#...#\n\
+             #.#.#.#.#\n\
+             #.#...#O#\n \
+             # ### # \n",
+        );
+    }
+
+    #[test]
+    fn finds_shortest_path_to_oxygen() {
+        let map = ShipMap::explore(&mut MazeDroid::new(MAZE)).unwrap();
+        assert_eq!(map.oxygen_distance(), Some(12));
+    }
+
+    #[test]
+    fn times_oxygen_fill() {
+        let mut droid = MazeDroid::new(
+            " ##   \n\
+             #D.## \n\
+             #.#..#\n\
+             #.O.# \n \
+             ###  \n",
+        );
+        let map = ShipMap::explore(&mut droid).unwrap();
+        assert_eq!(map.oxygen_fill_time(), Some(4));
+        assert_eq!(map.oxygen_distance(), Some(3));
+    }
+
+    #[test]
+    fn reports_unreachable_oxygen() {
+        let map = ShipMap::explore(&mut MazeDroid::new("###\n#D#\n###\n")).unwrap();
+        assert_eq!(map.oxygen_distance(), None);
+        assert_eq!(map.oxygen_fill_time(), None);
+    }
+}
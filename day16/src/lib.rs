@@ -0,0 +1,164 @@
+use aoc_utils::{read_string, AocError};
+
+pub const INPUT: &str = "input/input.txt";
+
+pub type Result<T> = aoc_utils::Result<T>;
+
+const BASE_PATTERN: [i64; 4] = [0, 1, 0, -1];
+const PHASES: usize = 100;
+const MESSAGE_LENGTH: usize = 8;
+const OFFSET_LENGTH: usize = 7;
+const REPEAT_COUNT: usize = 10000;
+
+fn parse_signal(input: &str) -> Result<Vec<i8>> {
+    input
+        .trim()
+        .chars()
+        .map(|c| {
+            c.to_digit(10)
+                .map(|digit| digit as i8)
+                .ok_or_else(|| AocError::Parse(format!("Invalid signal digit {:?}", c)))
+        })
+        .collect()
+}
+
+fn to_digit_string(signal: &[i8]) -> String {
+    signal.iter().map(|digit| digit.to_string()).collect()
+}
+
+/// One full phase: each output digit is the ones digit of the input multiplied by the base
+/// pattern with every value repeated once per position of that output digit.
+fn fft_phase(input: &[i8]) -> Vec<i8> {
+    (0..input.len())
+        .map(|output_index| {
+            let sum: i64 = input
+                .iter()
+                .enumerate()
+                .skip(output_index)
+                .map(|(index, digit)| {
+                    *digit as i64 * BASE_PATTERN[(index + 1) / (output_index + 1) % 4]
+                })
+                .sum();
+            (sum.abs() % 10) as i8
+        })
+        .collect()
+}
+
+/// One phase of the second half of a signal, passed in as just that suffix. Past the midpoint the
+/// pattern is all zeros up to the output digit and all ones after it, so each output digit is
+/// the sum of the input from its position to the end.
+fn fft_suffix_phase(input: &[i8]) -> Vec<i8> {
+    let mut output = vec![0; input.len()];
+    let mut sum: i8 = 0;
+    for (index, digit) in input.iter().enumerate().rev() {
+        sum = (sum + digit) % 10;
+        output[index] = sum;
+    }
+    output
+}
+
+pub fn solve_part1_from_str(input: &str) -> Result<String> {
+    let mut signal = parse_signal(input)?;
+    for _ in 0..PHASES {
+        signal = fft_phase(&signal);
+    }
+    Ok(to_digit_string(&signal[..MESSAGE_LENGTH.min(signal.len())]))
+}
+
+pub fn solve_part2_from_str(input: &str) -> Result<String> {
+    let signal = parse_signal(input)?;
+    let offset: usize = to_digit_string(&signal[..OFFSET_LENGTH.min(signal.len())]).parse()?;
+    let length = signal.len() * REPEAT_COUNT;
+    if offset < length / 2 || offset + MESSAGE_LENGTH > length {
+        return Err(AocError::InvalidInput(format!(
+            "Message offset {} is not in the second half of the {} digit signal",
+            offset, length
+        )));
+    }
+
+    let mut suffix: Vec<i8> = signal
+        .iter()
+        .copied()
+        .cycle()
+        .skip(offset)
+        .take(length - offset)
+        .collect();
+    for _ in 0..PHASES {
+        suffix = fft_suffix_phase(&suffix);
+    }
+    Ok(to_digit_string(&suffix[..MESSAGE_LENGTH]))
+}
+
+pub fn solve_part1() -> Result<String> {
+    solve_part1_from_str(&read_string(INPUT)?)
+}
+
+pub fn solve_part2() -> Result<String> {
+    solve_part2_from_str(&read_string(INPUT)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_phases() {
+        let mut signal = parse_signal("12345678").unwrap();
+        for expected in ["48226158", "34040438", "03415518", "01029498"].iter() {
+            signal = fft_phase(&signal);
+            assert_eq!(to_digit_string(&signal), *expected);
+        }
+    }
+
+    #[test]
+    fn runs_suffix_phases() {
+        // the second half of a full phase matches a suffix phase over just that half
+        let signal = parse_signal("80871224585914546619083218645595").unwrap();
+        let half = signal.len() / 2;
+        assert_eq!(
+            fft_suffix_phase(&signal[half..]),
+            fft_phase(&signal)[half..].to_vec()
+        );
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1_from_str("80871224585914546619083218645595").unwrap(),
+            "24176176"
+        );
+        assert_eq!(
+            solve_part1_from_str("19617804207202209144916044189917").unwrap(),
+            "73745418"
+        );
+        assert_eq!(
+            solve_part1_from_str("69317163492948606335995924319873").unwrap(),
+            "52432133"
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2_from_str("03036732577212944063491565474664").unwrap(),
+            "84462026"
+        );
+        assert_eq!(
+            solve_part2_from_str("02935109699940807407585447034323").unwrap(),
+            "78725270"
+        );
+        assert_eq!(
+            solve_part2_from_str("03081770884921959731165446850517").unwrap(),
+            "53553731"
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_signals() {
+        assert!(matches!(parse_signal("123x5"), Err(AocError::Parse(_))));
+        assert!(matches!(
+            solve_part2_from_str("12345678"),
+            Err(AocError::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,13 @@
+use std::process::ExitCode;
+
+use aoc_utils::run;
+use day16::{solve_part1, solve_part2, Result};
+
+fn main() -> ExitCode {
+    run(|| -> Result<()> {
+        println!("Part 1: {}", solve_part1()?);
+        println!("Part 2: {}", solve_part2()?);
+
+        Ok(())
+    })
+}
@@ -0,0 +1,310 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+use std::result;
+
+use aoc_utils::{parse_grid_chars, read_string, Direction4, Grid, Point2D};
+use intcode::{narrow_u8, Intcode};
+
+pub const INPUT: &str = "input/input.txt";
+
+pub type Result<T> = result::Result<T, Box<dyn Error>>;
+
+const MAX_FUNCTION_LENGTH: usize = 20;
+const FUNCTION_NAMES: [&str; 3] = ["A", "B", "C"];
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Move {
+    Left,
+    Right,
+    Forward(usize),
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Move::Left => write!(f, "L"),
+            Move::Right => write!(f, "R"),
+            Move::Forward(distance) => write!(f, "{}", distance),
+        }
+    }
+}
+
+fn is_scaffold(c: char) -> bool {
+    matches!(c, '#' | '^' | 'v' | '<' | '>')
+}
+
+fn robot_direction(c: char) -> Option<Direction4> {
+    match c {
+        '^' => Some(Direction4::North),
+        'v' => Some(Direction4::South),
+        '<' => Some(Direction4::West),
+        '>' => Some(Direction4::East),
+        _ => None,
+    }
+}
+
+fn join_moves(moves: &[Move]) -> String {
+    moves
+        .iter()
+        .map(|movement| movement.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+/// The camera's ASCII output as a grid, one row per line.
+pub fn parse_scaffold_map(output: &[i64]) -> Result<Grid<char>> {
+    let ascii = output
+        .iter()
+        .map(|value| Ok(char::from(narrow_u8(*value)?)))
+        .collect::<Result<String>>()?;
+    Ok(parse_grid_chars(&ascii))
+}
+
+/// Scaffold cells with scaffold on all four sides.
+pub fn find_intersections(map: &Grid<char>) -> Vec<(usize, usize)> {
+    map.iter_cells()
+        .filter(|(x, y, cell)| {
+            is_scaffold(**cell)
+                && map
+                    .neighbors4(*x, *y)
+                    .iter()
+                    .filter(|(_, _, neighbor)| is_scaffold(**neighbor))
+                    .count()
+                    == 4
+        })
+        .map(|(x, y, _)| (x, y))
+        .collect()
+}
+
+/// Follows the scaffold from the robot to its far end, turning only when it can't go straight.
+/// `None` if there's no robot or the scaffold loops back on itself without an end.
+pub fn find_path(map: &Grid<char>) -> Option<Vec<Move>> {
+    let (x, y, mut direction) = map
+        .iter_cells()
+        .find_map(|(x, y, cell)| robot_direction(*cell).map(|direction| (x, y, direction)))?;
+    let mut position = Point2D::new(x as i64, y as i64);
+    let scaffold_ahead = |position: Point2D<i64>, direction: Direction4| {
+        let next = position + Point2D::from(direction.to_delta());
+        next.x >= 0
+            && next.y >= 0
+            && map
+                .get(next.x as usize, next.y as usize)
+                .is_some_and(|cell| is_scaffold(*cell))
+    };
+
+    let mut moves = vec![];
+    let mut visited = HashSet::new();
+    loop {
+        if !scaffold_ahead(position, direction) {
+            if scaffold_ahead(position, direction.turn_left()) {
+                moves.push(Move::Left);
+                direction = direction.turn_left();
+            } else if scaffold_ahead(position, direction.turn_right()) {
+                moves.push(Move::Right);
+                direction = direction.turn_right();
+            } else {
+                return Some(moves);
+            }
+        }
+        if !visited.insert((position, direction)) {
+            return None;
+        }
+        let mut distance = 0;
+        while scaffold_ahead(position, direction) {
+            position = position + Point2D::from(direction.to_delta());
+            distance += 1;
+        }
+        moves.push(Move::Forward(distance));
+    }
+}
+
+/// Compresses `path` into a main routine calling up to three movement functions, none of which
+/// (nor the main routine) may be longer than 20 characters. Returns `(main, a, b, c)`.
+pub fn find_movement_functions(path: Vec<Move>) -> Option<(String, String, String, String)> {
+    let mut functions: Vec<&[Move]> = vec![];
+    let mut routine = vec![];
+    if !compress(&path, &mut functions, &mut routine) {
+        return None;
+    }
+
+    let function = |index: usize| {
+        functions
+            .get(index)
+            .map_or(String::new(), |f| join_moves(f))
+    };
+    let main = routine
+        .iter()
+        .map(|index| FUNCTION_NAMES[*index])
+        .collect::<Vec<&str>>()
+        .join(",");
+    Some((main, function(0), function(1), function(2)))
+}
+
+fn compress<'a>(
+    remaining: &'a [Move],
+    functions: &mut Vec<&'a [Move]>,
+    routine: &mut Vec<usize>,
+) -> bool {
+    if remaining.is_empty() {
+        return true;
+    }
+    // "A,B,C,..." has room for 10 calls in 20 characters
+    if routine.len() == MAX_FUNCTION_LENGTH / 2 {
+        return false;
+    }
+
+    for index in 0..functions.len() {
+        if remaining.starts_with(functions[index]) {
+            routine.push(index);
+            if compress(&remaining[functions[index].len()..], functions, routine) {
+                return true;
+            }
+            routine.pop();
+        }
+    }
+
+    if functions.len() < FUNCTION_NAMES.len() {
+        for length in 1..=remaining.len() {
+            let function = &remaining[..length];
+            if join_moves(function).len() > MAX_FUNCTION_LENGTH {
+                break;
+            }
+            functions.push(function);
+            routine.push(functions.len() - 1);
+            if compress(&remaining[length..], functions, routine) {
+                return true;
+            }
+            routine.pop();
+            functions.pop();
+        }
+    }
+    false
+}
+
+pub fn solve_part1_from_str(input: &str) -> Result<usize> {
+    let mut intcode: Intcode = input.parse()?;
+    let map = parse_scaffold_map(&intcode.execute(&[])?)?;
+    Ok(find_intersections(&map).iter().map(|(x, y)| x * y).sum())
+}
+
+pub fn solve_part2_from_str(input: &str) -> Result<i64> {
+    let mut intcode: Intcode = input.parse()?;
+    let map = parse_scaffold_map(&intcode.clone().execute(&[])?)?;
+    let path = find_path(&map).ok_or("No vacuum robot or path end found on the scaffold map")?;
+    let (main, a, b, c) =
+        find_movement_functions(path).ok_or("Path could not be split into movement functions")?;
+
+    // wake the robot up so it asks for movement instructions
+    intcode.integers.insert(0, 2);
+    let instructions: Vec<i64> = format!("{}\n{}\n{}\n{}\nn\n", main, a, b, c)
+        .bytes()
+        .map(i64::from)
+        .collect();
+    let output = intcode.execute(&instructions)?;
+    Ok(*output.last().ok_or("Vacuum robot reported no dust")?)
+}
+
+pub fn solve_part1() -> Result<usize> {
+    solve_part1_from_str(&read_string(INPUT)?)
+}
+
+pub fn solve_part2() -> Result<i64> {
+    solve_part2_from_str(&read_string(INPUT)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INTERSECTIONS_MAP: &str = "\
+..#..........
+..#..........
+#######...###
+#.#...#...#.#
+#############
+..#...#...#..
+..#####...^..
+";
+
+    const PATH_MAP: &str = "\
+#######...#####
+#.....#...#...#
+#.....#...#...#
+......#...#...#
+......#...###.#
+......#.....#.#
+^########...#.#
+......#.#...#.#
+......#########
+........#...#..
+....#########..
+....#...#......
+....#...#......
+....#...#......
+....#####......
+";
+
+    fn to_output(map: &str) -> Vec<i64> {
+        map.bytes().map(i64::from).collect()
+    }
+
+    #[test]
+    fn parses_scaffold_map() {
+        let map = parse_scaffold_map(&to_output(INTERSECTIONS_MAP)).unwrap();
+        assert_eq!((map.width(), map.height()), (13, 7));
+        assert_eq!(map.get(10, 6), Some(&'^'));
+        assert!(parse_scaffold_map(&[35, 256]).is_err());
+    }
+
+    #[test]
+    fn finds_intersections() {
+        let map = parse_scaffold_map(&to_output(INTERSECTIONS_MAP)).unwrap();
+        let intersections = find_intersections(&map);
+        assert_eq!(intersections, vec![(2, 2), (2, 4), (6, 4), (10, 4)]);
+        assert_eq!(intersections.iter().map(|(x, y)| x * y).sum::<usize>(), 76);
+    }
+
+    #[test]
+    fn finds_path() {
+        let map = parse_scaffold_map(&to_output(PATH_MAP)).unwrap();
+        assert_eq!(
+            join_moves(&find_path(&map).unwrap()),
+            "R,8,R,8,R,4,R,4,R,8,L,6,L,2,R,4,R,4,R,8,R,8,R,8,L,6,L,2"
+        );
+    }
+
+    #[test]
+    fn finds_no_path_around_closed_loops() {
+        let map = parse_grid_chars("^####\n#...#\n#####\n");
+        assert_eq!(find_path(&map), None);
+        assert_eq!(find_path(&parse_grid_chars("####\n")), None);
+    }
+
+    #[test]
+    fn finds_movement_functions() {
+        let map = parse_scaffold_map(&to_output(PATH_MAP)).unwrap();
+        let path = find_path(&map).unwrap();
+        let (main, a, b, c) = find_movement_functions(path.clone()).unwrap();
+        for routine in [&main, &a, &b, &c].iter() {
+            assert!(routine.len() <= MAX_FUNCTION_LENGTH);
+        }
+
+        let expanded: Vec<&str> = main
+            .split(',')
+            .map(|name| match name {
+                "A" => a.as_str(),
+                "B" => b.as_str(),
+                "C" => c.as_str(),
+                _ => panic!("unknown movement function {}", name),
+            })
+            .collect();
+        assert_eq!(expanded.join(","), join_moves(&path));
+    }
+
+    #[test]
+    fn fails_to_compress_irregular_paths() {
+        let path: Vec<Move> = (10..=40).map(Move::Forward).collect();
+        assert_eq!(find_movement_functions(path), None);
+    }
+}
@@ -0,0 +1,115 @@
+use std::io;
+
+use aoc_utils::read_string;
+
+mod search;
+
+use search::pairs;
+
+pub const INPUT: &str = "input/input.txt";
+
+fn parse_intcode(s: &str) -> Vec<i32> {
+    s.trim()
+        .split(',')
+        .map(|code| code.parse().unwrap())
+        .collect()
+}
+
+pub fn read_intcode(filename: &str) -> io::Result<Vec<i32>> {
+    Ok(parse_intcode(&read_string(filename)?))
+}
+
+fn run_intcode(intcode: &mut [i32]) {
+    let mut pointer = 0;
+
+    loop {
+        match intcode[pointer] {
+            1 => {
+                let a = intcode[intcode[pointer + 1] as usize];
+                let b = intcode[intcode[pointer + 2] as usize];
+                let target = intcode[pointer + 3] as usize;
+                intcode[target] = a + b;
+            }
+            2 => {
+                let a = intcode[intcode[pointer + 1] as usize];
+                let b = intcode[intcode[pointer + 2] as usize];
+                let target = intcode[pointer + 3] as usize;
+                intcode[target] = a * b;
+            }
+            99 => {
+                break;
+            }
+            invalid => panic!("Invalid opcode: {}", invalid),
+        }
+
+        pointer += 4;
+    }
+}
+
+pub fn solve_part1_from_str(input: &str) -> i32 {
+    let mut intcode = parse_intcode(input);
+    intcode[1] = 12;
+    intcode[2] = 2;
+    run_intcode(&mut intcode);
+    intcode[0]
+}
+
+pub fn solve_part2_from_str(input: &str) -> i32 {
+    let original_intcode = parse_intcode(input);
+    let (noun, verb) = pairs(0..99, 0..99)
+        .find(|&(noun, verb)| {
+            let mut intcode = original_intcode.clone();
+            intcode[1] = noun;
+            intcode[2] = verb;
+            run_intcode(&mut intcode);
+            intcode[0] == 19690720
+        })
+        .expect("Could not find a noun and verb that produced the target value");
+    100 * noun + verb
+}
+
+pub fn solve_part1() -> io::Result<i32> {
+    Ok(solve_part1_from_str(&read_string(INPUT)?))
+}
+
+pub fn solve_part2() -> io::Result<i32> {
+    Ok(solve_part2_from_str(&read_string(INPUT)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn reads_intcode() {
+        assert_eq!(
+            read_intcode(TEST_INPUT).unwrap(),
+            vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50]
+        );
+    }
+
+    #[test]
+    fn runs_intcodes() {
+        let mut intcode = vec![1, 0, 0, 0, 99];
+        run_intcode(&mut intcode);
+        assert_eq!(intcode, vec![2, 0, 0, 0, 99]);
+
+        let mut intcode = vec![2, 3, 0, 3, 99];
+        run_intcode(&mut intcode);
+        assert_eq!(intcode, vec![2, 3, 0, 6, 99]);
+
+        let mut intcode = vec![2, 4, 4, 5, 99, 0];
+        run_intcode(&mut intcode);
+        assert_eq!(intcode, vec![2, 4, 4, 5, 99, 9801]);
+
+        let mut intcode = vec![1, 1, 1, 4, 99, 5, 6, 0, 99];
+        run_intcode(&mut intcode);
+        assert_eq!(intcode, vec![30, 1, 1, 4, 2, 5, 6, 0, 99]);
+
+        let mut intcode = vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50];
+        run_intcode(&mut intcode);
+        assert_eq!(intcode, vec![3500, 9, 10, 70, 2, 3, 11, 0, 99, 30, 40, 50]);
+    }
+}
@@ -0,0 +1,23 @@
+/// Every combination of one item from `range_a` with one item from `range_b`, iterating
+/// `range_b` fastest.
+pub fn pairs<A, B>(range_a: A, range_b: B) -> impl Iterator<Item = (A::Item, B::Item)>
+where
+    A: Iterator,
+    A::Item: Clone,
+    B: Iterator + Clone,
+{
+    range_a.flat_map(move |a| range_b.clone().map(move |b| (a.clone(), b)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn yields_all_pairs() {
+        assert_eq!(
+            pairs(0..3, 0..2).collect::<Vec<(u32, u32)>>(),
+            vec![(0, 0), (0, 1), (1, 0), (1, 1), (2, 0), (2, 1)]
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use std::str::FromStr;
+
+use aoc_utils::{mod_inv, mul_mod, read_string, AocError, Matrix2x2};
+
+pub const INPUT: &str = "input/input.txt";
+
+pub type Result<T> = aoc_utils::Result<T>;
+
+const PART1_DECK_SIZE: i64 = 10007;
+const PART1_CARD: i64 = 2019;
+const PART2_DECK_SIZE: i64 = 119_315_717_514_047;
+const PART2_REPETITIONS: u64 = 101_741_582_076_661;
+const PART2_POSITION: i64 = 2020;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Shuffle {
+    DealIntoNew,
+    Cut(i64),
+    DealWithIncrement(i64),
+}
+
+impl FromStr for Shuffle {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Shuffle> {
+        let s = s.trim();
+        if s == "deal into new stack" {
+            Ok(Shuffle::DealIntoNew)
+        } else if let Some(n) = s.strip_prefix("cut ") {
+            Ok(Shuffle::Cut(n.parse()?))
+        } else if let Some(n) = s.strip_prefix("deal with increment ") {
+            Ok(Shuffle::DealWithIncrement(n.parse()?))
+        } else {
+            Err(AocError::Parse(format!("Unknown shuffle {:?}", s)))
+        }
+    }
+}
+
+impl Shuffle {
+    /// The shuffle as `(a, b)` such that the card at position `x` moves to `a * x + b`.
+    fn to_linear(self) -> (i64, i64) {
+        match self {
+            Shuffle::DealIntoNew => (-1, -1),
+            Shuffle::Cut(n) => (1, -n),
+            Shuffle::DealWithIncrement(n) => (n, 0),
+        }
+    }
+}
+
+pub fn parse_shuffles(s: &str) -> Result<Vec<Shuffle>> {
+    s.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.parse())
+        .collect()
+}
+
+/// Composes every shuffle into a single `(a, b)`, where the card at position `x` ends up at
+/// `(a * x + b) mod deck_size`.
+pub fn compose_linear(shuffles: &[Shuffle], deck_size: i64) -> (i64, i64) {
+    shuffles.iter().fold((1, 0), |(a, b), shuffle| {
+        let (c, d) = shuffle.to_linear();
+        (
+            mul_mod(a, c, deck_size),
+            (mul_mod(b, c, deck_size) + d).rem_euclid(deck_size),
+        )
+    })
+}
+
+fn inverse(x: i64, deck_size: i64) -> Result<i64> {
+    mod_inv(x, deck_size).ok_or_else(|| {
+        AocError::InvalidInput(format!(
+            "Shuffle can't be reversed, {} has no inverse modulo {}",
+            x, deck_size
+        ))
+    })
+}
+
+/// The card that ends up at `position` after shuffling a `deck_size` deck with `shuffles`
+/// `repetitions` times.
+fn card_at_position(
+    shuffles: &[Shuffle],
+    deck_size: i64,
+    repetitions: u64,
+    position: i64,
+) -> Result<i64> {
+    let (a, b) = compose_linear(shuffles, deck_size);
+    // undo the whole shuffle with y -> (y - b) / a, then repeat that `repetitions` times
+    let a_inverse = inverse(a, deck_size)?;
+    Ok(
+        Matrix2x2::linear(a_inverse, mul_mod(-b, a_inverse, deck_size))
+            .pow(repetitions, deck_size)
+            .apply(position, deck_size),
+    )
+}
+
+pub fn solve_part1_from_str(input: &str) -> Result<i64> {
+    let (a, b) = compose_linear(&parse_shuffles(input)?, PART1_DECK_SIZE);
+    Ok((mul_mod(a, PART1_CARD, PART1_DECK_SIZE) + b).rem_euclid(PART1_DECK_SIZE))
+}
+
+pub fn solve_part2_from_str(input: &str) -> Result<i64> {
+    card_at_position(
+        &parse_shuffles(input)?,
+        PART2_DECK_SIZE,
+        PART2_REPETITIONS,
+        PART2_POSITION,
+    )
+}
+
+pub fn solve_part1() -> Result<i64> {
+    solve_part1_from_str(&read_string(INPUT)?)
+}
+
+pub fn solve_part2() -> Result<i64> {
+    solve_part2_from_str(&read_string(INPUT)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The deck in order from top to bottom after shuffling a factory order deck.
+    fn shuffled_deck(shuffles: &str, deck_size: i64) -> Vec<i64> {
+        let (a, b) = compose_linear(&parse_shuffles(shuffles).unwrap(), deck_size);
+        let mut deck = vec![0; deck_size as usize];
+        for card in 0..deck_size {
+            deck[(mul_mod(a, card, deck_size) + b).rem_euclid(deck_size) as usize] = card;
+        }
+        deck
+    }
+
+    #[test]
+    fn parses_shuffles() {
+        assert_eq!(
+            parse_shuffles("deal into new stack\ncut -2\ndeal with increment 7\n").unwrap(),
+            vec![
+                Shuffle::DealIntoNew,
+                Shuffle::Cut(-2),
+                Shuffle::DealWithIncrement(7)
+            ]
+        );
+        assert!(matches!(
+            parse_shuffles("deal with 7"),
+            Err(AocError::Parse(_))
+        ));
+        assert!(matches!(parse_shuffles("cut x"), Err(AocError::Parse(_))));
+    }
+
+    #[test]
+    fn shuffles_examples() {
+        assert_eq!(
+            shuffled_deck("deal into new stack", 10),
+            vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 0]
+        );
+        assert_eq!(
+            shuffled_deck("cut -4", 10),
+            vec![6, 7, 8, 9, 0, 1, 2, 3, 4, 5]
+        );
+        assert_eq!(
+            shuffled_deck(
+                "deal with increment 7\ndeal into new stack\ndeal into new stack",
+                10
+            ),
+            vec![0, 3, 6, 9, 2, 5, 8, 1, 4, 7]
+        );
+        assert_eq!(
+            shuffled_deck("cut 6\ndeal with increment 7\ndeal into new stack", 10),
+            vec![3, 0, 7, 4, 1, 8, 5, 2, 9, 6]
+        );
+        assert_eq!(
+            shuffled_deck("deal with increment 7\ndeal with increment 9\ncut -2", 10),
+            vec![6, 3, 0, 7, 4, 1, 8, 5, 2, 9]
+        );
+        assert_eq!(
+            shuffled_deck(
+                "deal into new stack\ncut -2\ndeal with increment 7\ncut 8\ncut -4\n\
+                 deal with increment 7\ncut 3\ndeal with increment 9\ndeal with increment 3\n\
+                 cut -1",
+                10
+            ),
+            vec![9, 2, 5, 8, 1, 4, 7, 0, 3, 6]
+        );
+    }
+
+    #[test]
+    fn finds_card_after_repeated_shuffles() {
+        let shuffles = parse_shuffles(
+            "deal into new stack\ncut -2\ndeal with increment 7\ncut 8\ndeal with increment 3",
+        )
+        .unwrap();
+        let deck_size = 10007;
+        let (a, b) = compose_linear(&shuffles, deck_size);
+        for repetitions in 0..5 {
+            // shuffle every card one repetition at a time to find which lands on position 2020
+            let card = (0..deck_size)
+                .find(|card| {
+                    let mut position = *card;
+                    for _ in 0..repetitions {
+                        position = (mul_mod(a, position, deck_size) + b).rem_euclid(deck_size);
+                    }
+                    position == 2020
+                })
+                .unwrap();
+            assert_eq!(
+                card_at_position(&shuffles, deck_size, repetitions, 2020).unwrap(),
+                card
+            );
+        }
+        assert!(matches!(
+            card_at_position(&[Shuffle::DealWithIncrement(2)], 10, 3, 0),
+            Err(AocError::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::VecDeque;
+use std::error::Error;
+use std::result;
+
+use aoc_utils::read_string;
+use intcode::Intcode;
+
+pub const INPUT: &str = "input/input.txt";
+
+pub type Result<T> = result::Result<T, Box<dyn Error>>;
+
+const NETWORK_SIZE: usize = 50;
+const NAT_ADDRESS: i64 = 255;
+/// What a computer reads when no packets are waiting for it.
+const NO_PACKET: i64 = -1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Packet {
+    pub destination: i64,
+    pub x: i64,
+    pub y: i64,
+}
+
+struct Computer {
+    intcode: Intcode,
+    input_queue: VecDeque<i64>,
+    /// Outputs that don't yet make up a whole packet.
+    pending_output: Vec<i64>,
+}
+
+pub struct Network {
+    computers: Vec<Computer>,
+    /// The last packet sent to the NAT.
+    nat: Option<Packet>,
+}
+
+impl Network {
+    /// Boots `size` copies of `intcode`, giving each its network address as its first input.
+    pub fn new(intcode: &Intcode, size: usize) -> Network {
+        Network {
+            computers: (0..size)
+                .map(|address| Computer {
+                    intcode: intcode.clone(),
+                    input_queue: VecDeque::from(vec![address as i64]),
+                    pending_output: vec![],
+                })
+                .collect(),
+            nat: None,
+        }
+    }
+
+    fn is_idle(&self) -> bool {
+        self.computers
+            .iter()
+            .all(|computer| computer.input_queue.is_empty())
+    }
+
+    fn send(&mut self, packet: Packet) -> Result<()> {
+        if packet.destination == NAT_ADDRESS {
+            self.nat = Some(packet);
+            return Ok(());
+        }
+        let computer = usize::try_from(packet.destination)
+            .ok()
+            .and_then(|address| self.computers.get_mut(address))
+            .ok_or_else(|| format!("Packet sent to unknown address {}", packet.destination))?;
+        computer.input_queue.extend(&[packet.x, packet.y]);
+        Ok(())
+    }
+
+    /// Runs every computer once until it waits for more input, then routes the packets they sent.
+    /// Computers without queued packets are given `-1`. Returns every packet sent this round.
+    pub fn step(&mut self) -> Result<Vec<Packet>> {
+        if self
+            .computers
+            .iter()
+            .all(|computer| computer.intcode.halted)
+        {
+            return Err(From::from("Every computer on the network has halted"));
+        }
+        let mut packets = vec![];
+        for computer in self.computers.iter_mut() {
+            if computer.intcode.halted {
+                continue;
+            }
+            let inputs: Vec<i64> = if computer.input_queue.is_empty() {
+                vec![NO_PACKET]
+            } else {
+                computer.input_queue.drain(..).collect()
+            };
+            let (output, _) = computer.intcode.run(&inputs, None)?;
+            computer.pending_output.extend(output);
+
+            let whole_packets = computer.pending_output.len() / 3 * 3;
+            for packet in computer
+                .pending_output
+                .drain(..whole_packets)
+                .collect::<Vec<_>>()
+                .chunks(3)
+            {
+                packets.push(Packet {
+                    destination: packet[0],
+                    x: packet[1],
+                    y: packet[2],
+                });
+            }
+        }
+        for packet in packets.iter() {
+            self.send(*packet)?;
+        }
+        Ok(packets)
+    }
+
+    /// The y value of the first packet sent to the NAT.
+    pub fn first_nat_packet(&mut self) -> Result<i64> {
+        loop {
+            let was_idle = self.is_idle();
+            let packets = self.step()?;
+            if let Some(packet) = packets
+                .iter()
+                .find(|packet| packet.destination == NAT_ADDRESS)
+            {
+                return Ok(packet.y);
+            }
+            if was_idle && packets.is_empty() {
+                return Err(From::from("Network went idle before sending to the NAT"));
+            }
+        }
+    }
+
+    /// The first y value the NAT delivers to address 0 twice in a row. Whenever the network is
+    /// idle the NAT wakes it up by resending the last packet it received to address 0.
+    pub fn first_repeated_nat_wake_up(&mut self) -> Result<i64> {
+        let mut last_y = None;
+        loop {
+            let was_idle = self.is_idle();
+            let packets = self.step()?;
+            if !was_idle || !packets.is_empty() {
+                continue;
+            }
+            let packet = self
+                .nat
+                .ok_or("Network went idle before sending to the NAT")?;
+            if last_y == Some(packet.y) {
+                return Ok(packet.y);
+            }
+            last_y = Some(packet.y);
+            self.send(Packet {
+                destination: 0,
+                ..packet
+            })?;
+        }
+    }
+}
+
+pub fn solve_part1_from_str(input: &str) -> Result<i64> {
+    Network::new(&input.parse()?, NETWORK_SIZE).first_nat_packet()
+}
+
+pub fn solve_part2_from_str(input: &str) -> Result<i64> {
+    Network::new(&input.parse()?, NETWORK_SIZE).first_repeated_nat_wake_up()
+}
+
+pub fn solve_part1() -> Result<i64> {
+    solve_part1_from_str(&read_string(INPUT)?)
+}
+
+pub fn solve_part2() -> Result<i64> {
+    solve_part2_from_str(&read_string(INPUT)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Address 0 starts by sending (7, 42) to address 1. Every computer forwards each packet it
+    /// receives to the NAT with its own address added to y.
+    const ECHO_PROGRAM: &str = "3,100,1008,100,0,102,1006,102,15,104,1,104,7,104,42,\
+                                3,101,1008,101,-1,102,1005,102,15,3,103,1,103,100,103,\
+                                104,255,4,101,4,103,1105,1,15";
+
+    #[test]
+    fn routes_packets() {
+        let mut network = Network::new(&ECHO_PROGRAM.parse().unwrap(), 2);
+        assert_eq!(
+            network.step().unwrap(),
+            vec![Packet {
+                destination: 1,
+                x: 7,
+                y: 42
+            }]
+        );
+        assert_eq!(network.computers[1].input_queue, vec![7, 42]);
+        assert_eq!(
+            network.step().unwrap(),
+            vec![Packet {
+                destination: 255,
+                x: 7,
+                y: 43
+            }]
+        );
+        assert!(network.is_idle());
+        assert_eq!(network.step().unwrap(), vec![]);
+    }
+
+    #[test]
+    fn finds_first_nat_packet() {
+        let mut network = Network::new(&ECHO_PROGRAM.parse().unwrap(), 2);
+        assert_eq!(network.first_nat_packet().unwrap(), 43);
+    }
+
+    #[test]
+    fn finds_first_repeated_nat_wake_up() {
+        let mut network = Network::new(&ECHO_PROGRAM.parse().unwrap(), 2);
+        assert_eq!(network.first_repeated_nat_wake_up().unwrap(), 43);
+    }
+
+    #[test]
+    fn rejects_unknown_addresses() {
+        let mut network = Network::new(&ECHO_PROGRAM.parse().unwrap(), 1);
+        assert!(network.step().is_err());
+    }
+}
@@ -0,0 +1,19 @@
+use std::env;
+use std::process::ExitCode;
+
+use aoc_utils::run;
+use day23::{solve_part1, solve_part2, Result, INPUT};
+use intcode::{read_intcode, repl};
+
+fn main() -> ExitCode {
+    run(|| -> Result<()> {
+        if env::args().any(|arg| arg == "--repl") {
+            return repl(read_intcode(INPUT)?);
+        }
+
+        println!("Part 1: {}", solve_part1()?);
+        println!("Part 2: {}", solve_part2()?);
+
+        Ok(())
+    })
+}
@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use aoc_utils::{min_by_key_stable, read_string, AocError, Point2D};
+
+pub const INPUT: &str = "input/input.txt";
+
+pub type Result<T> = aoc_utils::Result<T>;
+
+#[derive(Debug, PartialEq)]
+pub struct CrossedWires {
+    wires: Vec<Vec<Move>>,
+}
+
+pub type Point = Point2D<i32>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Intersection {
+    pub point: Point,
+    /// Combined steps both wires take to reach the point.
+    pub steps: u32,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Intersections {
+    intersections: Vec<Intersection>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Move {
+    direction: Direction,
+    distance: i32,
+}
+
+#[derive(Debug, PartialEq)]
+enum Direction {
+    Up,
+    Down,
+    Right,
+    Left,
+}
+
+impl CrossedWires {
+    pub fn find_intersections(&self) -> Intersections {
+        let mut intersections: HashMap<Point, u32> = HashMap::new();
+
+        let mut occupied_points: HashMap<Point, u32> = HashMap::new();
+        for (wire_index, wire) in self.wires.iter().enumerate() {
+            let mut steps = 0;
+            let mut end_point = Point { x: 0, y: 0 };
+            for movement in wire.iter() {
+                let mut point = end_point;
+                for _ in 0..movement.distance {
+                    match movement.direction {
+                        Direction::Up => point.y += 1,
+                        Direction::Down => point.y -= 1,
+                        Direction::Right => point.x += 1,
+                        Direction::Left => point.x -= 1,
+                    };
+                    steps += 1;
+                    if wire_index == 0 {
+                        occupied_points.insert(point, steps);
+                    } else {
+                        if let Some(first_wire_steps) = occupied_points.get(&point) {
+                            intersections.insert(point, first_wire_steps + steps);
+                        }
+                    }
+                }
+                end_point = point;
+            }
+        }
+
+        let mut intersections: Vec<Intersection> = intersections
+            .into_iter()
+            .map(|(point, steps)| Intersection { point, steps })
+            .collect();
+        // sorted so that ties under a metric always resolve to the same intersection
+        intersections.sort_by_key(|intersection| (intersection.point.x, intersection.point.y));
+        Intersections { intersections }
+    }
+}
+
+impl Intersections {
+    /// The intersection with the smallest value of `metric`, or `None` if the wires never cross.
+    pub fn closest_by<F, K>(&self, metric: F) -> Option<&Intersection>
+    where
+        F: Fn(&Intersection) -> K,
+        K: Ord,
+    {
+        min_by_key_stable(self.intersections.iter(), |intersection| {
+            metric(intersection)
+        })
+    }
+}
+
+impl FromStr for CrossedWires {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<CrossedWires> {
+        let mut wires = s
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+        let first_moves = wires
+            .next()
+            .ok_or_else(|| AocError::Parse("First wire not found in input".to_string()))?;
+        let second_moves = wires
+            .next()
+            .ok_or_else(|| AocError::Parse("Second wire not found in input".to_string()))?;
+
+        Ok(CrossedWires {
+            wires: vec![
+                get_moves_from_string(first_moves)?,
+                get_moves_from_string(second_moves)?,
+            ],
+        })
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = AocError;
+
+    fn try_from(c: char) -> Result<Direction> {
+        match c {
+            'U' => Ok(Direction::Up),
+            'D' => Ok(Direction::Down),
+            'R' => Ok(Direction::Right),
+            'L' => Ok(Direction::Left),
+            _ => Err(AocError::Parse(format!("Could not parse direction: {}", c))),
+        }
+    }
+}
+
+fn get_moves_from_string(moves_string: &str) -> Result<Vec<Move>> {
+    let moves_strings = moves_string.split(",");
+    let mut moves = vec![];
+
+    for wire_move in moves_strings {
+        let mut wire_move = wire_move.chars();
+        let direction = Direction::try_from(
+            wire_move
+                .next()
+                .ok_or_else(|| AocError::Parse("Invalid empty wire move".to_string()))?,
+        )?;
+        let distance: i32 = wire_move.collect::<String>().parse()?;
+
+        moves.push(Move {
+            direction,
+            distance,
+        });
+    }
+    Ok(moves)
+}
+
+pub fn read_wires(filename: &str) -> Result<CrossedWires> {
+    let wires = read_string(filename)?;
+    wires.parse()
+}
+
+pub fn solve_part1_from_str(input: &str) -> Result<i32> {
+    let wires: CrossedWires = input.parse()?;
+    let manhattan_distance =
+        |intersection: &Intersection| intersection.point.manhattan_distance_from_origin();
+    let closest = *wires
+        .find_intersections()
+        .closest_by(manhattan_distance)
+        .ok_or(AocError::NoSolutionFound)?;
+    Ok(manhattan_distance(&closest))
+}
+
+pub fn solve_part2_from_str(input: &str) -> Result<i32> {
+    let wires: CrossedWires = input.parse()?;
+    let closest = *wires
+        .find_intersections()
+        .closest_by(|intersection| intersection.steps)
+        .ok_or(AocError::NoSolutionFound)?;
+    Ok(closest.steps as i32)
+}
+
+pub fn solve_part1() -> Result<i32> {
+    solve_part1_from_str(&read_string(INPUT)?)
+}
+
+pub fn solve_part2() -> Result<i32> {
+    solve_part2_from_str(&read_string(INPUT)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT1: &str = "input/test1.txt";
+    // const TEST_INPUT2: &str = "input/test2.txt";
+    // const TEST_INPUT3: &str = "input/test3.txt";
+
+    #[test]
+    fn reads_wires() {
+        assert_eq!(
+            read_wires(TEST_INPUT1).unwrap(),
+            CrossedWires {
+                wires: vec![
+                    vec![
+                        Move {
+                            direction: Direction::Right,
+                            distance: 8
+                        },
+                        Move {
+                            direction: Direction::Up,
+                            distance: 5
+                        },
+                        Move {
+                            direction: Direction::Left,
+                            distance: 5
+                        },
+                        Move {
+                            direction: Direction::Down,
+                            distance: 3
+                        },
+                    ],
+                    vec![
+                        Move {
+                            direction: Direction::Up,
+                            distance: 7
+                        },
+                        Move {
+                            direction: Direction::Right,
+                            distance: 6
+                        },
+                        Move {
+                            direction: Direction::Down,
+                            distance: 4
+                        },
+                        Move {
+                            direction: Direction::Left,
+                            distance: 4
+                        },
+                    ],
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn skips_blank_lines_and_comments() {
+        assert_eq!(
+            "\nR8,U5,L5,D3\n# second wire\nU7,R6,D4,L4\n# trailing comment\n"
+                .parse::<CrossedWires>()
+                .unwrap(),
+            read_wires(TEST_INPUT1).unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_wires() {
+        let error = |s: &str| s.parse::<CrossedWires>().unwrap_err().to_string();
+        assert_eq!(error(""), "First wire not found in input");
+        assert_eq!(error("R8,U5\n"), "Second wire not found in input");
+        assert_eq!(error("R8,,U5\nU7"), "Invalid empty wire move");
+        assert_eq!(error("R8,X5\nU7"), "Could not parse direction: X");
+    }
+
+    #[test]
+    fn finds_closest_intersection_by_metric() {
+        let intersections = read_wires(TEST_INPUT1).unwrap().find_intersections();
+        let chebyshev_distance = |intersection: &Intersection| {
+            intersection.point.x.abs().max(intersection.point.y.abs())
+        };
+        assert_eq!(
+            intersections.closest_by(chebyshev_distance),
+            Some(&Intersection {
+                point: Point { x: 3, y: 3 },
+                steps: 40
+            })
+        );
+        assert_eq!(
+            intersections.closest_by(|intersection| intersection.steps),
+            Some(&Intersection {
+                point: Point { x: 6, y: 5 },
+                steps: 30
+            })
+        );
+    }
+}
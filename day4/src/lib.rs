@@ -0,0 +1,195 @@
+use std::ops::RangeInclusive;
+
+use aoc_utils::{read_single_line, AocError};
+use rand::distributions::Uniform;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+pub const INPUT: &str = "input/input.txt";
+
+pub type Result<T> = aoc_utils::Result<T>;
+
+/// Parses a puzzle range written as `min-max`.
+pub fn parse_range(s: &str) -> Result<RangeInclusive<u32>> {
+    let (min, max) = s
+        .trim()
+        .split_once('-')
+        .ok_or_else(|| AocError::Parse(format!("Expected a range like 123-456, got {:?}", s)))?;
+    Ok(min.parse()?..=max.parse()?)
+}
+
+/// Reads a `min-max` range from a file holding nothing else.
+pub fn read_range(filename: &str) -> Result<RangeInclusive<u32>> {
+    parse_range(&read_single_line(filename)?)
+}
+
+pub fn is_valid_part1(num: u32) -> bool {
+    let num_string = num.to_string();
+    let mut previous = None;
+    let mut has_double = false;
+    let mut decreasing = false;
+    for c in num_string.chars() {
+        match previous {
+            None => previous = Some(c),
+            Some(p) => {
+                if p == c {
+                    has_double = true;
+                }
+                if p.to_digit(10) > c.to_digit(10) {
+                    decreasing = true;
+                    break;
+                }
+                previous = Some(c);
+            }
+        }
+    }
+    has_double && !decreasing
+}
+
+pub fn is_valid_part2(num: u32) -> bool {
+    // too lazy to DRY it up
+    let num_string = num.to_string();
+    let mut previous = None;
+    let mut has_double = false;
+    let mut matching_group_count = 1;
+    let mut decreasing = false;
+    for c in num_string.chars() {
+        match previous {
+            None => previous = Some(c),
+            Some(p) => {
+                if p == c {
+                    matching_group_count += 1;
+                } else {
+                    if matching_group_count == 2 {
+                        has_double = true;
+                    }
+                    matching_group_count = 1;
+                }
+                if p.to_digit(10) > c.to_digit(10) {
+                    decreasing = true;
+                    break;
+                }
+                previous = Some(c);
+            }
+        }
+    }
+    (matching_group_count == 2 || has_double) && !decreasing
+}
+
+pub fn is_valid(num: u32, part2: bool) -> bool {
+    if part2 {
+        is_valid_part2(num)
+    } else {
+        is_valid_part1(num)
+    }
+}
+
+/// Fraction of `samples` randomly picked numbers in `range` that are valid passwords. The same
+/// `seed` always picks the same numbers. `None` if `range` is empty or `samples` is 0.
+pub fn estimate_valid_density(
+    range: RangeInclusive<u32>,
+    part2: bool,
+    samples: usize,
+    seed: u64,
+) -> Option<f64> {
+    if range.is_empty() || samples == 0 {
+        return None;
+    }
+    let numbers = Uniform::new_inclusive(range.start(), range.end());
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut valid = 0;
+    for _ in 0..samples {
+        if is_valid(rng.sample(numbers), part2) {
+            valid += 1;
+        }
+    }
+    Some(valid as f64 / samples as f64)
+}
+
+fn count_valid(range: RangeInclusive<u32>, part2: bool) -> u32 {
+    let mut counter = 0;
+    for num in range {
+        if is_valid(num, part2) {
+            counter += 1;
+        }
+    }
+    counter
+}
+
+pub fn solve_part1_from_str(input: &str) -> Result<u32> {
+    Ok(count_valid(parse_range(input)?, false))
+}
+
+pub fn solve_part2_from_str(input: &str) -> Result<u32> {
+    Ok(count_valid(parse_range(input)?, true))
+}
+
+pub fn solve_part1() -> Result<u32> {
+    Ok(count_valid(read_range(INPUT)?, false))
+}
+
+pub fn solve_part2() -> Result<u32> {
+    Ok(count_valid(read_range(INPUT)?, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn reads_range() {
+        assert_eq!(read_range(TEST_INPUT).unwrap(), 111_110..=111_130);
+        assert_eq!(parse_range(" 1-20 ").unwrap(), 1..=20);
+        assert!(parse_range("1 20").is_err());
+        assert!(parse_range("1-twenty").is_err());
+        assert!(read_range("input/missing.txt").is_err());
+    }
+
+    #[test]
+    fn counts_valid_passwords_in_range() {
+        assert_eq!(solve_part1_from_str("111110-111130").unwrap(), 17);
+        assert_eq!(solve_part2_from_str("111110-111130").unwrap(), 1);
+        assert!(solve_part1_from_str("111110").is_err());
+    }
+
+    #[test]
+    fn estimates_valid_density() {
+        let range = 111_000..=299_999;
+        for part2 in [false, true].iter() {
+            let exact = range.clone().filter(|num| is_valid(*num, *part2)).count() as f64
+                / (range.end() - range.start() + 1) as f64;
+            let estimate = estimate_valid_density(range.clone(), *part2, 100_000, 2019).unwrap();
+            assert!((estimate - exact).abs() < 0.001);
+            assert_eq!(
+                estimate_valid_density(range.clone(), *part2, 1000, 42),
+                estimate_valid_density(range.clone(), *part2, 1000, 42)
+            );
+        }
+    }
+
+    #[test]
+    fn estimates_nothing_without_samples_or_numbers() {
+        assert_eq!(
+            estimate_valid_density(111_000..=299_999, false, 0, 2019),
+            None
+        );
+        assert_eq!(
+            estimate_valid_density(RangeInclusive::new(2, 1), false, 1000, 2019),
+            None
+        );
+        assert_eq!(
+            estimate_valid_density(u32::MAX - 10..=u32::MAX, false, 1000, 2019),
+            Some(0.0)
+        );
+        assert_eq!(
+            estimate_valid_density(111_111..=111_111, true, 10, 2019),
+            Some(0.0)
+        );
+        assert_eq!(
+            estimate_valid_density(111_111..=111_111, false, 10, 2019),
+            Some(1.0)
+        );
+    }
+}
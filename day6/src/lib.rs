@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+
+use aoc_utils::{bfs, read_string, AocError};
+use petgraph::graph::NodeIndex;
+use petgraph::{Direction, Graph};
+
+pub const INPUT: &str = "input/input.txt";
+
+pub type Result<T> = aoc_utils::Result<T>;
+
+pub struct OrbitMap {
+    graph: Graph<String, ()>,
+    map: HashMap<String, NodeIndex>,
+}
+
+pub fn parse_orbit_map(input: &str) -> Result<OrbitMap> {
+    let mut graph = Graph::<String, ()>::new();
+    let mut map: HashMap<String, NodeIndex> = HashMap::new();
+
+    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+        let (mass_name, orbiter_name) = line
+            .trim()
+            .split_once(')')
+            .filter(|(mass, orbiter)| !mass.is_empty() && !orbiter.is_empty())
+            .ok_or_else(|| {
+                AocError::Parse(format!("Invalid line {:?}, expected MASS)ORBITER", line))
+            })?;
+        let mass_name = mass_name.to_string();
+        let orbiter_name = orbiter_name.to_string();
+
+        let mass_index = match map.get(&mass_name) {
+            None => {
+                let index = graph.add_node(mass_name.clone());
+                map.insert(mass_name, index);
+                index
+            }
+            Some(index) => *index,
+        };
+        let orbiter_index = match map.get(&orbiter_name) {
+            None => {
+                let index = graph.add_node(orbiter_name.clone());
+                map.insert(orbiter_name, index);
+                index
+            }
+            Some(index) => *index,
+        };
+        graph.update_edge(orbiter_index, mass_index, ());
+    }
+
+    Ok(OrbitMap { graph, map })
+}
+
+impl OrbitMap {
+    fn all_depths(&self) -> HashMap<NodeIndex, u32> {
+        let mut depths: HashMap<NodeIndex, u32> = HashMap::new();
+        for node in self.graph.node_indices() {
+            // walk towards COM until reaching a node whose depth is already known
+            let mut unknown = vec![];
+            let mut current = node;
+            let mut depth = loop {
+                if let Some(depth) = depths.get(&current) {
+                    break *depth;
+                }
+                match self
+                    .graph
+                    .neighbors_directed(current, Direction::Outgoing)
+                    .next()
+                {
+                    None => {
+                        depths.insert(current, 0);
+                        break 0;
+                    }
+                    Some(mass) => {
+                        unknown.push(current);
+                        current = mass;
+                    }
+                }
+            };
+            for orbiter in unknown.into_iter().rev() {
+                depth += 1;
+                depths.insert(orbiter, depth);
+            }
+        }
+        depths
+    }
+
+    fn node(&self, name: &str) -> Result<NodeIndex> {
+        self.map
+            .get(name)
+            .copied()
+            .ok_or_else(|| AocError::InvalidInput(format!("{} not found in orbit map", name)))
+    }
+
+    /// The object that `name` directly orbits.
+    fn mass_of(&self, name: &str) -> Result<NodeIndex> {
+        self.graph
+            .neighbors_directed(self.node(name)?, Direction::Outgoing)
+            .next()
+            .ok_or_else(|| AocError::InvalidInput(format!("{} is not orbiting a mass", name)))
+    }
+
+    /// Names of every object on the shortest path from `from` to `to`, including both ends.
+    pub fn transfer_path(&self, from: &str, to: &str) -> Result<Vec<String>> {
+        let destination = self.node(to)?;
+        let path = bfs(
+            self.node(from)?,
+            |node| *node == destination,
+            |node| self.graph.neighbors_undirected(*node).collect(),
+        )
+        .ok_or(AocError::NoSolutionFound)?;
+        Ok(path
+            .into_iter()
+            .map(|node| self.graph[node].clone())
+            .collect())
+    }
+}
+
+fn get_orbit_count_checksum(orbit_map: &OrbitMap) -> u32 {
+    orbit_map.all_depths().values().sum()
+}
+
+fn get_orbital_transfers(
+    orbit_map: &OrbitMap,
+    source: NodeIndex,
+    destination: NodeIndex,
+) -> Option<usize> {
+    let path = bfs(
+        source,
+        |node| *node == destination,
+        |node| orbit_map.graph.neighbors_undirected(*node).collect(),
+    )?;
+    Some(path.len() - 1)
+}
+
+pub fn solve_part1_from_str(input: &str) -> Result<u32> {
+    let orbit_map = parse_orbit_map(input)?;
+    Ok(get_orbit_count_checksum(&orbit_map))
+}
+
+pub fn solve_part2_from_str(input: &str) -> Result<usize> {
+    let orbit_map = parse_orbit_map(input)?;
+    let you_mass = orbit_map.mass_of("YOU")?;
+    let san_mass = orbit_map.mass_of("SAN")?;
+    let transfers = get_orbital_transfers(&orbit_map, you_mass, san_mass);
+    transfers.ok_or(AocError::NoSolutionFound)
+}
+
+pub fn solve_part1() -> Result<u32> {
+    solve_part1_from_str(&read_string(INPUT)?)
+}
+
+pub fn solve_part2() -> Result<usize> {
+    solve_part2_from_str(&read_string(INPUT)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "input/test.txt";
+    const TEST_INPUT2: &str = "input/test2.txt";
+
+    #[test]
+    fn reads_orbit_map() {
+        let orbit_map = parse_orbit_map(&read_string(TEST_INPUT).unwrap()).unwrap();
+        assert_eq!(
+            format!("{:?}", orbit_map.graph),
+            "Graph { \
+             Ty: \"Directed\", \
+             node_count: 12, \
+             edge_count: 11, \
+             edges: (1, 0), (2, 1), (3, 2), (4, 3), (5, 4), (6, 1), (7, 6), \
+             (8, 3), (9, 4), (10, 9), (11, 10), \
+             node weights: {\
+             0: \"COM\", \
+             1: \"B\", \
+             2: \"C\", \
+             3: \"D\", \
+             4: \"E\", \
+             5: \"F\", \
+             6: \"G\", \
+             7: \"H\", \
+             8: \"I\", \
+             9: \"J\", \
+             10: \"K\", \
+             11: \"L\"\
+             } \
+             }",
+        )
+    }
+
+    #[test]
+    fn gets_all_depths() {
+        let orbit_map = parse_orbit_map(&read_string(TEST_INPUT).unwrap()).unwrap();
+        let depths = orbit_map.all_depths();
+        assert_eq!(depths.len(), 12);
+        for (name, depth) in [("COM", 0), ("B", 1), ("D", 3), ("G", 2), ("L", 7)].iter() {
+            assert_eq!(depths[&orbit_map.map[*name]], *depth);
+        }
+    }
+
+    #[test]
+    fn gets_orbit_count_checksum() {
+        let orbit_map = parse_orbit_map(&read_string(TEST_INPUT).unwrap()).unwrap();
+        assert_eq!(get_orbit_count_checksum(&orbit_map), 42)
+    }
+
+    #[test]
+    fn finds_orbital_transfers_between_objects() {
+        let orbit_map = parse_orbit_map(&read_string(TEST_INPUT2).unwrap()).unwrap();
+        assert_eq!(
+            get_orbital_transfers(
+                &orbit_map,
+                *orbit_map.map.get("K").unwrap(),
+                *orbit_map.map.get("I").unwrap()
+            )
+            .unwrap(),
+            4
+        );
+
+        assert_eq!(
+            get_orbital_transfers(
+                &orbit_map,
+                *orbit_map.map.get("K").unwrap(),
+                *orbit_map.map.get("J").unwrap()
+            )
+            .unwrap(),
+            1
+        );
+
+        assert_eq!(
+            get_orbital_transfers(
+                &orbit_map,
+                *orbit_map.map.get("YOU").unwrap(),
+                *orbit_map.map.get("L").unwrap()
+            )
+            .unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn finds_transfer_path_between_objects() {
+        let orbit_map = parse_orbit_map(&read_string(TEST_INPUT2).unwrap()).unwrap();
+        assert_eq!(
+            orbit_map.transfer_path("K", "I").unwrap(),
+            vec!["K", "J", "E", "D", "I"]
+        );
+        assert_eq!(orbit_map.transfer_path("L", "L").unwrap(), vec!["L"]);
+        assert!(matches!(
+            orbit_map.transfer_path("K", "Z"),
+            Err(AocError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_orbit_maps() {
+        let error = |s: &str| parse_orbit_map(s).err().unwrap().to_string();
+        assert_eq!(
+            error("COM)B\nBC"),
+            "Invalid line \"BC\", expected MASS)ORBITER"
+        );
+        assert_eq!(
+            error("COM)"),
+            "Invalid line \"COM)\", expected MASS)ORBITER"
+        );
+        assert!(parse_orbit_map("COM)B\n\nB)C\n").is_ok());
+
+        assert_eq!(
+            solve_part2_from_str("YOU)A\nCOM)SAN")
+                .unwrap_err()
+                .to_string(),
+            "YOU is not orbiting a mass"
+        );
+        assert_eq!(
+            solve_part2_from_str("COM)YOU\nSAN)A")
+                .unwrap_err()
+                .to_string(),
+            "SAN is not orbiting a mass"
+        );
+    }
+}
@@ -1,11 +1,9 @@
-use std::collections::VecDeque;
 use std::convert::TryFrom;
 use std::error::Error;
-use std::fs::File;
-use std::io::prelude::*;
 use std::result;
 use std::str::FromStr;
 
+use aoc_utils::read_string;
 use num_enum::TryFromPrimitive;
 
 type Result<T> = result::Result<T, Box<dyn Error>>;
@@ -43,6 +41,13 @@ impl TryFrom<i32> for Instruction {
                 )?,
             })
         }
+        if modes_integer / 10_i32.pow(opcode.parameter_count()) != 0 {
+            return Err(From::from(format!(
+                "Instruction {} sets parameter modes beyond its parameter count of {}",
+                integer,
+                opcode.parameter_count()
+            )));
+        }
         Ok(Instruction {
             opcode,
             parameter_modes,
@@ -209,11 +214,7 @@ impl Intcode {
 }
 
 pub fn read_intcode(filename: &str) -> Result<Intcode> {
-    let mut file = File::open(filename)?;
-    let mut intcode_string = String::new();
-    file.read_to_string(&mut intcode_string)?;
-
-    Ok(intcode_string.parse()?)
+    read_string(filename)?.parse()
 }
 
 #[cfg(test)]
@@ -257,6 +258,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rejects_extra_parameter_modes() {
+        assert_eq!(
+            Instruction::try_from(10004).unwrap_err().to_string(),
+            "Instruction 10004 sets parameter modes beyond its parameter count of 1"
+        );
+        assert!(Instruction::try_from(199).is_err());
+        assert!(Instruction::try_from(1104).is_err());
+        assert!(Instruction::try_from(104).is_ok());
+        assert!(Instruction::try_from(10002).is_ok());
+    }
+
     #[test]
     fn executes_intcodes() {
         let mut intcode = Intcode::new(vec![1, 0, 0, 0, 99]);
@@ -0,0 +1,269 @@
+use std::error::Error;
+use std::result;
+
+use aoc_utils::{permutations, read_string};
+
+pub mod intcode;
+
+use intcode::{read_intcode, Intcode};
+
+pub const INPUT: &str = "input/input.txt";
+
+pub type Result<T> = result::Result<T, Box<dyn Error>>;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Amplifier {
+    intcode: Intcode,
+}
+
+impl Amplifier {
+    fn new(intcode: Intcode) -> Amplifier {
+        Amplifier {
+            intcode,
+        }
+    }
+
+    fn reset_intcode(&mut self, intcode: Intcode) {
+        self.intcode = intcode;
+    }
+
+    fn execute(&mut self, input: i32) -> Result<Vec<i32>> {
+        let output = self.intcode.execute(&[input])?;
+        Ok(output)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AmplificationCircuit {
+    amplifiers: Vec<Amplifier>,
+    intcode: Intcode,
+}
+
+impl AmplificationCircuit {
+    pub fn new(
+        intcode: Intcode,
+        amplifier_count: usize,
+    ) -> AmplificationCircuit {
+        AmplificationCircuit {
+            amplifiers: (0..amplifier_count)
+                .map(|_| Amplifier::new(intcode.clone()))
+                .collect(),
+            intcode,
+        }
+    }
+
+    pub fn from_file(path: &str, amplifier_count: usize) -> Result<AmplificationCircuit> {
+        Ok(AmplificationCircuit::new(
+            read_intcode(path)?,
+            amplifier_count,
+        ))
+    }
+
+    fn set_phase_settings(&mut self, phase_settings: &[i32]) -> Result<()> {
+        if phase_settings.len() != self.amplifiers.len() {
+            return Err(From::from(format!(
+                "Got {} phase settings for a circuit of {} amplifiers",
+                phase_settings.len(),
+                self.amplifiers.len()
+            )));
+        }
+        for (index, phase_setting) in phase_settings.iter().enumerate() {
+            self.amplifiers[index].execute(*phase_setting)?;
+        }
+        Ok(())
+    }
+
+    fn reset_circuit(&mut self) {
+        for amplifier in self.amplifiers.iter_mut() {
+            amplifier.reset_intcode(self.intcode.clone());
+        }
+    }
+
+    fn execute_circuit(&mut self, input_signal: i32) -> Result<i32> {
+        let mut input = input_signal;
+        while !self
+            .amplifiers
+            .last()
+            .ok_or("Circuit has no amplifiers")?
+            .intcode
+            .halted
+        {
+            for amplifier in self.amplifiers.iter_mut() {
+                input = amplifier.execute(input)?[0];
+            }
+        }
+        Ok(input)
+    }
+
+    /// Runs one amplifier per phase setting, so `phase_setting_options` must have as many entries
+    /// as the circuit has amplifiers.
+    pub fn find_max_output(
+        &mut self,
+        input_signal: i32,
+        phase_setting_options: &[i32],
+    ) -> Result<i32> {
+        let mut max_output = 0;
+
+        for permutation in permutations(phase_setting_options) {
+            self.set_phase_settings(&permutation)?;
+
+            let output = self.execute_circuit(input_signal)?;
+            if output > max_output {
+                max_output = output;
+            }
+            self.reset_circuit();
+        }
+
+        Ok(max_output)
+    }
+
+    /// Tries phase settings in permutation order, stopping at the first whose output from an
+    /// input signal of 0 reaches `threshold` instead of scoring every permutation.
+    pub fn find_output_reaching(
+        &mut self,
+        threshold: i32,
+        phases: &[i32],
+    ) -> Result<Option<(Vec<i32>, i32)>> {
+        for permutation in permutations(phases) {
+            self.set_phase_settings(&permutation)?;
+            let output = self.execute_circuit(0)?;
+            self.reset_circuit();
+            if output >= threshold {
+                return Ok(Some((permutation, output)));
+            }
+        }
+        Ok(None)
+    }
+}
+
+pub fn solve_part1_from_str(input: &str) -> Result<i32> {
+    let mut circuit = AmplificationCircuit::new(input.parse()?, 5);
+    circuit.find_max_output(0, &[0, 1, 2, 3, 4])
+}
+
+pub fn solve_part2_from_str(input: &str) -> Result<i32> {
+    let mut circuit = AmplificationCircuit::new(input.parse()?, 5);
+    circuit.find_max_output(0, &[5, 6, 7, 8, 9])
+}
+
+pub fn solve_part1() -> Result<i32> {
+    solve_part1_from_str(&read_string(INPUT)?)
+}
+
+pub fn solve_part2() -> Result<i32> {
+    solve_part2_from_str(&read_string(INPUT)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT1: &str = "input/test1.txt";
+    const TEST_INPUT2: &str = "input/test2.txt";
+    const TEST_INPUT3: &str = "input/test3.txt";
+    const TEST_INPUT4: &str = "input/test4.txt";
+    const TEST_INPUT5: &str = "input/test5.txt";
+
+    #[test]
+    fn builds_circuit_from_file() {
+        let mut circuit = AmplificationCircuit::from_file(TEST_INPUT1, 5).unwrap();
+        assert_eq!(circuit.amplifiers.len(), 5);
+        assert_eq!(circuit.intcode, read_intcode(TEST_INPUT1).unwrap());
+        circuit.set_phase_settings(&[4, 3, 2, 1, 0]).unwrap();
+        assert_eq!(circuit.execute_circuit(0).unwrap(), 43210);
+
+        assert!(AmplificationCircuit::from_file("input/missing.txt", 5).is_err());
+    }
+
+    #[test]
+    fn executes_amplifier_circuits() {
+        let mut circuit = AmplificationCircuit::from_file(TEST_INPUT1, 5).unwrap();
+        circuit.set_phase_settings(&[4, 3, 2, 1, 0]).unwrap();
+        assert_eq!(circuit.execute_circuit(0).unwrap(), 43210);
+
+        let mut circuit = AmplificationCircuit::from_file(TEST_INPUT2, 5).unwrap();
+        circuit.set_phase_settings(&[0, 1, 2, 3, 4]).unwrap();
+        assert_eq!(circuit.execute_circuit(0).unwrap(), 54321);
+
+        let mut circuit = AmplificationCircuit::from_file(TEST_INPUT3, 5).unwrap();
+        circuit.set_phase_settings(&[1, 0, 4, 3, 2]).unwrap();
+        assert_eq!(circuit.execute_circuit(0).unwrap(), 65210);
+    }
+
+    #[test]
+    fn finds_max_output_of_circuits() {
+        let inputs = [TEST_INPUT1, TEST_INPUT2, TEST_INPUT3];
+        let outputs = [43210, 54321, 65210];
+        for (input, output) in inputs.iter().zip(outputs.iter()) {
+            let mut circuit = AmplificationCircuit::from_file(input, 5).unwrap();
+            assert_eq!(
+                circuit.find_max_output(0, &[0, 1, 2, 3, 4]).unwrap(),
+                *output
+            );
+        }
+    }
+
+    #[test]
+    fn matches_amplifier_count_to_phase_settings() {
+        // each amplifier outputs ten times its input plus its phase setting
+        let mut circuit = AmplificationCircuit::from_file(TEST_INPUT1, 3).unwrap();
+        assert_eq!(circuit.find_max_output(0, &[0, 1, 2]).unwrap(), 210);
+        circuit.set_phase_settings(&[1, 2, 3]).unwrap();
+        assert_eq!(circuit.execute_circuit(0).unwrap(), 123);
+
+        let mut circuit = AmplificationCircuit::from_file(TEST_INPUT1, 5).unwrap();
+        assert_eq!(
+            circuit
+                .find_max_output(0, &[0, 1, 2])
+                .unwrap_err()
+                .to_string(),
+            "Got 3 phase settings for a circuit of 5 amplifiers"
+        );
+    }
+
+    #[test]
+    fn finds_output_reaching_threshold() {
+        let mut circuit = AmplificationCircuit::from_file(TEST_INPUT1, 5).unwrap();
+        // the very first permutation is enough, so no others are tried
+        assert_eq!(
+            circuit.find_output_reaching(0, &[0, 1, 2, 3, 4]).unwrap(),
+            Some((vec![0, 1, 2, 3, 4], 1234))
+        );
+        assert_eq!(
+            circuit
+                .find_output_reaching(43210, &[0, 1, 2, 3, 4])
+                .unwrap(),
+            Some((vec![4, 3, 2, 1, 0], 43210))
+        );
+        assert_eq!(
+            circuit
+                .find_output_reaching(43211, &[0, 1, 2, 3, 4])
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn executes_feedback_loop_amplifier_circuits() {
+        let mut circuit = AmplificationCircuit::from_file(TEST_INPUT4, 5).unwrap();
+        circuit.set_phase_settings(&[9, 8, 7, 6, 5]).unwrap();
+        assert_eq!(circuit.execute_circuit(0).unwrap(), 139629729);
+
+        let mut circuit = AmplificationCircuit::from_file(TEST_INPUT5, 5).unwrap();
+        circuit.set_phase_settings(&[9, 7, 8, 5, 6]).unwrap();
+        assert_eq!(circuit.execute_circuit(0).unwrap(), 18216);
+    }
+
+    #[test]
+    fn finds_max_outputs_of_feedback_loop_circuits() {
+        let inputs = [TEST_INPUT4, TEST_INPUT5];
+        let outputs = [139629729, 18216];
+        for (input, output) in inputs.iter().zip(outputs.iter()) {
+            let mut circuit = AmplificationCircuit::from_file(input, 5).unwrap();
+            assert_eq!(
+                circuit.find_max_output(0, &[5, 6, 7, 8, 9]).unwrap(),
+                *output
+            );
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use std::fmt;
+
+use aoc_utils::{min_by_key_stable, read_string, AocError};
+
+pub const INPUT: &str = "input/input.txt";
+
+pub type Result<T> = aoc_utils::Result<T>;
+
+const TRANSPARENT: u8 = 2;
+
+#[derive(Debug, PartialEq)]
+struct Image {
+    layers: Vec<Layer>,
+}
+
+impl Image {
+    fn final_layer(&self) -> Layer {
+        let mut layer_iter = self.layers.iter();
+        let mut final_layer = (*layer_iter.next().expect("No layers in image")).clone();
+        for layer in layer_iter {
+            for (row_index, row) in layer.rows.iter().enumerate() {
+                for (col_index, pixel) in row.iter().enumerate() {
+                    if final_layer.rows[row_index][col_index] == TRANSPARENT {
+                        final_layer.rows[row_index][col_index] = *pixel;
+                    }
+                }
+            }
+        }
+        final_layer
+    }
+
+    /// Checks that there is at least one layer, that every layer is `width` by `height` and that
+    /// they only hold black, white or transparent pixels.
+    fn validate(&self, width: usize, height: usize) -> Result<()> {
+        if self.layers.is_empty() {
+            return Err(AocError::InvalidInput("Image has no layers".to_string()));
+        }
+        for (index, layer) in self.layers.iter().enumerate() {
+            let pixel_count: usize = layer.rows.iter().map(|row| row.len()).sum();
+            if pixel_count != width * height
+                || layer.rows.len() != height
+                || layer.rows.iter().any(|row| row.len() != width)
+            {
+                return Err(AocError::InvalidInput(format!(
+                    "Layer {} has {} pixels, expected {}x{}",
+                    index, pixel_count, width, height
+                )));
+            }
+            if let Some(pixel) = layer.rows.iter().flatten().find(|pixel| **pixel > 2) {
+                return Err(AocError::InvalidInput(format!(
+                    "Layer {} has invalid pixel {}",
+                    index, pixel
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for layer in self.layers.iter() {
+            writeln!(f, "{}", layer)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Layer {
+    rows: Vec<Vec<u8>>,
+}
+
+impl fmt::Display for Layer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows.iter() {
+            let row_string = row
+                .iter()
+                .map(|pixel| pixel.to_string())
+                .collect::<Vec<String>>()
+                .join("");
+            writeln!(f, "{}", row_string)?;
+        }
+        Ok(())
+    }
+}
+
+impl Layer {
+    fn count_pixels(&self, pixel: u8) -> u32 {
+        self.rows
+            .iter()
+            .flatten()
+            .fold(0, |acc, p| if *p == pixel { acc + 1 } else { acc })
+    }
+
+    fn opaque_pixel_count(&self) -> usize {
+        self.rows
+            .iter()
+            .flatten()
+            .filter(|pixel| **pixel != TRANSPARENT)
+            .count()
+    }
+}
+
+fn read_image_file(filename: &str) -> Result<String> {
+    Ok(read_string(filename)?.trim().to_string())
+}
+
+fn parse_image(image_string: String, width: usize, height: usize) -> Result<Image> {
+    let mut layers = vec![];
+    let mut layer = vec![];
+    let mut row: Vec<u8> = vec![];
+    for pixel in image_string.chars() {
+        let digit = pixel.to_digit(10).ok_or_else(|| {
+            AocError::InvalidInput(format!("Invalid pixel character {:?}", pixel))
+        })?;
+        row.push(digit as u8);
+        if row.len() == width {
+            layer.push(row);
+            row = vec![];
+        }
+        if layer.len() == height {
+            layers.push(Layer { rows: layer });
+            layer = vec![];
+        }
+    }
+    if !row.is_empty() {
+        layer.push(row);
+    }
+    if !layer.is_empty() {
+        layers.push(Layer { rows: layer });
+    }
+    Ok(Image { layers })
+}
+
+pub fn solve_part1_from_str(input: &str) -> Result<u32> {
+    let image = parse_image(input.trim().to_string(), 25, 6)?;
+    image.validate(25, 6)?;
+    let fewest_zero_index = min_by_key_stable(0..image.layers.len(), |index| {
+        image.layers[*index].count_pixels(0)
+    })
+    .expect("No image layers created");
+    let fewest_zero_layer = &image.layers[fewest_zero_index];
+    Ok(fewest_zero_layer.count_pixels(1) * fewest_zero_layer.count_pixels(2))
+}
+
+pub fn solve_part2_from_str(input: &str) -> Result<String> {
+    let image = parse_image(input.trim().to_string(), 25, 6)?;
+    image.validate(25, 6)?;
+    let final_layer = image.final_layer();
+    if final_layer.opaque_pixel_count() == 0 {
+        return Err(AocError::InvalidInput(
+            "Every pixel of the decoded image is transparent".to_string(),
+        ));
+    }
+    Ok(format!("{}", final_layer))
+}
+
+pub fn solve_part1() -> Result<u32> {
+    solve_part1_from_str(&read_image_file(INPUT)?)
+}
+
+pub fn solve_part2() -> Result<String> {
+    solve_part2_from_str(&read_image_file(INPUT)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn reads_image() {
+        let image_string = read_image_file(TEST_INPUT).unwrap();
+        assert_eq!(
+            parse_image(image_string, 3, 2).unwrap(),
+            Image {
+                layers: vec![
+                    Layer {
+                        rows: vec![vec![1, 2, 3], vec![4, 5, 6]],
+                    },
+                    Layer {
+                        rows: vec![vec![7, 8, 9], vec![0, 1, 2]],
+                    },
+                ],
+            }
+        )
+    }
+
+    #[test]
+    fn counts_opaque_pixels() {
+        let image_string = read_image_file(TEST_INPUT).unwrap();
+        let final_layer = parse_image(image_string, 3, 2).unwrap().final_layer();
+        assert_eq!(final_layer.rows, vec![vec![1, 8, 3], vec![4, 5, 6]]);
+        assert_eq!(final_layer.opaque_pixel_count(), 6);
+
+        let image = parse_image("222222".to_string(), 3, 1).unwrap();
+        assert_eq!(image.final_layer().opaque_pixel_count(), 0);
+    }
+
+    #[test]
+    fn validates_image() {
+        let image = parse_image("120012210".to_string(), 3, 1).unwrap();
+        assert!(image.validate(3, 1).is_ok());
+
+        let image = parse_image("12345678901".to_string(), 3, 2).unwrap();
+        assert_eq!(
+            image.validate(3, 2).unwrap_err().to_string(),
+            "Layer 0 has invalid pixel 3"
+        );
+
+        let image = parse_image("12001221001".to_string(), 3, 2).unwrap();
+        assert_eq!(
+            image.validate(3, 2).unwrap_err().to_string(),
+            "Layer 1 has 5 pixels, expected 3x2"
+        );
+
+        let image = parse_image(String::new(), 3, 2).unwrap();
+        assert_eq!(
+            image.validate(3, 2).unwrap_err().to_string(),
+            "Image has no layers"
+        );
+        assert!(solve_part1_from_str("").is_err());
+        assert!(solve_part2_from_str("\n").is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_pixel_characters() {
+        assert_eq!(
+            parse_image("120x12".to_string(), 3, 2)
+                .unwrap_err()
+                .to_string(),
+            "Invalid pixel character 'x'"
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use std::error::Error;
+
+use aoc_utils::{read_string, AocError};
+use intcode::Intcode;
+
+pub const INPUT: &str = "input/input.txt";
+
+pub type Result<T> = aoc_utils::Result<T>;
+
+fn run_boost(input: &str, mode: i64) -> Result<i64> {
+    let intcode_error = |err: Box<dyn Error>| AocError::IntcodeError(err.to_string());
+    let mut intcode: Intcode = input.parse().map_err(intcode_error)?;
+    // anything besides the keycode is a list of malfunctioning opcodes
+    let output = intcode.execute_once(&[mode]).map_err(intcode_error)?;
+    output.ok_or(AocError::NoSolutionFound)
+}
+
+pub fn solve_part1_from_str(input: &str) -> Result<i64> {
+    run_boost(input, 1)
+}
+
+pub fn solve_part2_from_str(input: &str) -> Result<i64> {
+    run_boost(input, 2)
+}
+
+pub fn solve_part1() -> Result<i64> {
+    solve_part1_from_str(&read_string(INPUT)?)
+}
+
+pub fn solve_part2() -> Result<i64> {
+    solve_part2_from_str(&read_string(INPUT)?)
+}
@@ -1,29 +1,21 @@
+use std::env;
 use std::error::Error;
-use std::result;
+use std::process::ExitCode;
 
-mod intcode;
+use aoc_utils::run;
+use day9::{solve_part1, solve_part2, INPUT};
+use intcode::{read_intcode, repl};
 
-use intcode::{Intcode, read_intcode};
+// the repl reports intcode's boxed errors, which aren't `AocError`s
+fn main() -> ExitCode {
+    run(|| -> Result<(), Box<dyn Error>> {
+        if env::args().any(|arg| arg == "--repl") {
+            return repl(read_intcode(INPUT)?);
+        }
 
-const INPUT: &str = "input/input.txt";
+        println!("Part 1: {}", solve_part1()?);
+        println!("Part 2: {}", solve_part2()?);
 
-type Result<T> = result::Result<T, Box<dyn Error>>;
-
-fn solve_part1() -> Result<i64> {
-    let mut intcode = read_intcode(INPUT)?;
-    let output = intcode.execute(&[1]).expect("Failed to execute intcode");
-    Ok(output[output.len() - 1])
-}
-
-fn solve_part2() -> Result<i64> {
-    let mut intcode = read_intcode(INPUT)?;
-    let output = intcode.execute(&[2]).expect("Failed to execute intcode");
-    Ok(output[output.len() - 1])
-}
-
-fn main() -> Result<()> {
-    println!("Part 1: {}", solve_part1()?);
-    println!("Part 2: {}", solve_part2()?);
-
-    Ok(())
+        Ok(())
+    })
 }
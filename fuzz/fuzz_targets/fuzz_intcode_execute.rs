@@ -0,0 +1,15 @@
+#![no_main]
+use std::str;
+
+use libfuzzer_sys::fuzz_target;
+
+use intcode::Intcode;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(source) = str::from_utf8(data) {
+        if let Ok(mut intcode) = source.parse::<Intcode>() {
+            // malformed programs must error cleanly; the step limit stops infinite loops
+            let _ = intcode.execute_n_steps(1000);
+        }
+    }
+});
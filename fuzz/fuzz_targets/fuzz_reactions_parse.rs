@@ -0,0 +1,13 @@
+#![no_main]
+use std::str;
+
+use libfuzzer_sys::fuzz_target;
+
+use day14::Reactions;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(source) = str::from_utf8(data) {
+        // any input must either parse or error cleanly
+        let _ = source.parse::<Reactions>();
+    }
+});
@@ -0,0 +1,1232 @@
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, prelude::*};
+use std::iter;
+use std::result;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use aoc_utils::{parse_numbers_at, read_string};
+use num_enum::TryFromPrimitive;
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+/// How many instructions `execute_with_deadline` runs between checks of the clock.
+const DEADLINE_CHECK_INTERVAL: usize = 1000;
+/// The most addresses `to_source` will write out, so a program that wrote to a huge address
+/// can't make it exhaust memory.
+const MAX_SOURCE_ADDRESSES: usize = 1 << 20;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Intcode {
+    pub integers: HashMap<usize, i64>,
+    pub pointer: usize,
+    pub halted: bool,
+    pub relative_base: i64,
+    pub count_opcodes: bool,
+    opcode_counts: HashMap<Opcode, u64>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct MemoryStats {
+    pub cells_written: usize,
+    pub highest_address: Option<usize>,
+}
+
+/// Returned (boxed) by `execute_with_deadline` when the program is still running at the deadline.
+#[derive(Debug, PartialEq)]
+pub struct Timeout {
+    pub deadline: Duration,
+    pub steps: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Instruction {
+    opcode: Opcode,
+    parameter_modes: Vec<ParameterMode>,
+}
+
+impl TryFrom<i64> for Instruction {
+    type Error = Box<dyn Error>;
+
+    fn try_from(integer: i64) -> Result<Self> {
+        let opcode: Opcode = Opcode::try_from((integer % 100) as u8)?;
+        let modes_integer = integer / 100;
+        let mut parameter_modes = vec![];
+        for parameter_index in 0..opcode.parameter_count() {
+            parameter_modes.push(ParameterMode::try_from(
+                (modes_integer % (10_i64.pow(parameter_index + 1)) / 10_i64.pow(parameter_index))
+                    as u8,
+            )?)
+        }
+        Ok(Instruction {
+            opcode,
+            parameter_modes,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, TryFromPrimitive)]
+#[repr(u8)]
+pub enum Opcode {
+    Add = 1,
+    Mult = 2,
+    Input = 3,
+    Output = 4,
+    JumpIfTrue = 5,
+    JumpIfFalse = 6,
+    LessThan = 7,
+    Equals = 8,
+    RelativeBaseOffset = 9,
+    Halt = 99,
+}
+
+impl Opcode {
+    pub fn parameter_count(&self) -> u32 {
+        match self {
+            Opcode::Add => 3,
+            Opcode::Mult => 3,
+            Opcode::Input => 1,
+            Opcode::Output => 1,
+            Opcode::JumpIfTrue => 2,
+            Opcode::JumpIfFalse => 2,
+            Opcode::LessThan => 3,
+            Opcode::Equals => 3,
+            Opcode::RelativeBaseOffset => 1,
+            Opcode::Halt => 0,
+        }
+    }
+
+    pub fn target_parameter_index(&self) -> Option<usize> {
+        match self {
+            Opcode::Add => Some(2),
+            Opcode::Mult => Some(2),
+            Opcode::Input => Some(0),
+            Opcode::Output => None,
+            Opcode::JumpIfTrue => None,
+            Opcode::JumpIfFalse => None,
+            Opcode::LessThan => Some(2),
+            Opcode::Equals => Some(2),
+            Opcode::RelativeBaseOffset => None,
+            Opcode::Halt => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum StopReason {
+    Halted,
+    NeedInput,
+    OutputLimit,
+}
+
+#[derive(Debug, PartialEq)]
+enum Step {
+    Continue,
+    Output(i64),
+    NeedInput,
+    Halted,
+}
+
+#[derive(Debug, PartialEq, TryFromPrimitive)]
+#[repr(u8)]
+pub enum ParameterMode {
+    Position = 0,
+    Immediate = 1,
+    Relative = 2,
+}
+
+impl FromStr for Intcode {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Intcode> {
+        let integers = parse_numbers_at(s, ',')
+            .map_err(|err| format!("{}\n{}", err.message, point_at(s, err.start, err.len)))?;
+        Ok(Intcode::from_slice(&integers))
+    }
+}
+
+impl fmt::Display for Intcode {
+    // fails, like `to_source`, if memory reaches past `MAX_SOURCE_ADDRESSES`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_source().map_err(|_| fmt::Error)?)
+    }
+}
+
+impl fmt::Display for Timeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Intcode did not halt within {:?} ({} steps)",
+            self.deadline, self.steps
+        )
+    }
+}
+
+impl Error for Timeout {}
+
+impl Intcode {
+    fn new(integers: HashMap<usize, i64>) -> Intcode {
+        Intcode {
+            integers,
+            pointer: 0,
+            halted: false,
+            relative_base: 0,
+            count_opcodes: false,
+            opcode_counts: HashMap::new(),
+        }
+    }
+
+    pub fn from_slice(integers: &[i64]) -> Intcode {
+        Intcode::new(integers.iter().copied().enumerate().collect())
+    }
+
+    pub fn memory_stats(&self) -> MemoryStats {
+        MemoryStats {
+            cells_written: self.integers.len(),
+            highest_address: self.integers.keys().max().copied(),
+        }
+    }
+
+    /// `(address, self value, other value)` for every address where the two machines' memory
+    /// differs, in address order. Unwritten addresses count as 0.
+    pub fn diff(&self, other: &Intcode) -> Vec<(usize, i64, i64)> {
+        let addresses: BTreeSet<usize> = self
+            .integers
+            .keys()
+            .chain(other.integers.keys())
+            .copied()
+            .collect();
+        addresses
+            .into_iter()
+            .filter_map(|address| {
+                let value = *self.integers.get(&address).unwrap_or(&0);
+                let other_value = *other.integers.get(&address).unwrap_or(&0);
+                if value == other_value {
+                    None
+                } else {
+                    Some((address, value, other_value))
+                }
+            })
+            .collect()
+    }
+
+    /// Number of times each opcode has executed while `count_opcodes` was enabled.
+    pub fn opcode_counts(&self) -> &HashMap<Opcode, u64> {
+        &self.opcode_counts
+    }
+
+    fn count_opcode(&mut self, opcode: Opcode) {
+        if self.count_opcodes {
+            *self.opcode_counts.entry(opcode).or_insert(0) += 1;
+        }
+    }
+
+    fn load_parameters(&mut self, pointer: usize, instruction: &Instruction) -> Result<Vec<i64>> {
+        (0..instruction.opcode.parameter_count() as usize)
+            .map(|parameter_index| {
+                let mut integer = *self
+                    .integers
+                    .entry(pointer + parameter_index + 1)
+                    .or_insert(0);
+                match instruction.parameter_modes[parameter_index] {
+                    ParameterMode::Position => match instruction.opcode.target_parameter_index() {
+                        Some(target_parameter_index)
+                            if target_parameter_index == parameter_index => {}
+                        _ => {
+                            integer = *self.integers.entry(to_address(integer)?).or_insert(0);
+                        }
+                    },
+                    ParameterMode::Relative => {
+                        integer = checked_add(self.relative_base, integer)?;
+                        match instruction.opcode.target_parameter_index() {
+                            Some(target_parameter_index)
+                                if target_parameter_index == parameter_index => {}
+                            _ => {
+                                integer = *self.integers.entry(to_address(integer)?).or_insert(0);
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+                Ok(integer)
+            })
+            .collect()
+    }
+
+    fn step(&mut self, inputs: &mut impl Iterator<Item = i64>) -> Result<Step> {
+        let instruction = Instruction::try_from(*self.integers.entry(self.pointer).or_insert(0))?;
+        let parameters = self.load_parameters(self.pointer, &instruction)?;
+        let mut jump_pointer: Option<usize> = None;
+        let mut step = Step::Continue;
+
+        match instruction.opcode {
+            Opcode::Add => {
+                self.integers.insert(
+                    to_address(parameters[2])?,
+                    checked_add(parameters[0], parameters[1])?,
+                );
+            }
+            Opcode::Mult => {
+                let product = parameters[0].checked_mul(parameters[1]).ok_or_else(|| {
+                    format!(
+                        "Overflow multiplying {} by {}",
+                        parameters[0], parameters[1]
+                    )
+                })?;
+                self.integers.insert(to_address(parameters[2])?, product);
+            }
+            Opcode::Input => match inputs.next() {
+                Some(input) => {
+                    self.integers.insert(to_address(parameters[0])?, input);
+                }
+                None => return Ok(Step::NeedInput), // pause execution to wait for more input
+            },
+            Opcode::Output => {
+                step = Step::Output(parameters[0]);
+            }
+            Opcode::JumpIfTrue => {
+                if parameters[0] != 0 {
+                    jump_pointer = Some(to_address(parameters[1])?);
+                }
+            }
+            Opcode::JumpIfFalse => {
+                if parameters[0] == 0 {
+                    jump_pointer = Some(to_address(parameters[1])?);
+                }
+            }
+            Opcode::LessThan => {
+                if parameters[0] < parameters[1] {
+                    self.integers.insert(to_address(parameters[2])?, 1);
+                } else {
+                    self.integers.insert(to_address(parameters[2])?, 0);
+                }
+            }
+            Opcode::Equals => {
+                if parameters[0] == parameters[1] {
+                    self.integers.insert(to_address(parameters[2])?, 1);
+                } else {
+                    self.integers.insert(to_address(parameters[2])?, 0);
+                }
+            }
+            Opcode::RelativeBaseOffset => {
+                self.relative_base = checked_add(self.relative_base, parameters[0])?;
+            }
+            Opcode::Halt => {
+                self.count_opcode(instruction.opcode);
+                self.halted = true;
+                return Ok(Step::Halted);
+            }
+        }
+        self.count_opcode(instruction.opcode);
+
+        match jump_pointer {
+            Some(jump_pointer) => self.pointer = jump_pointer,
+            None => self.pointer += 1 + instruction.opcode.parameter_count() as usize,
+        }
+
+        Ok(step)
+    }
+
+    /// Describes the instruction at `address`, returning the text and the instruction's length.
+    pub fn disassemble(&self, address: usize) -> Result<(String, usize)> {
+        let integer_at = |address: usize| *self.integers.get(&address).unwrap_or(&0);
+        let instruction = Instruction::try_from(integer_at(address))?;
+        let parameters: Vec<String> = instruction
+            .parameter_modes
+            .iter()
+            .enumerate()
+            .map(|(parameter_index, mode)| {
+                let integer = integer_at(address + parameter_index + 1);
+                match mode {
+                    ParameterMode::Position => format!("[{}]", integer),
+                    ParameterMode::Immediate => integer.to_string(),
+                    ParameterMode::Relative => format!("[rb{:+}]", integer),
+                }
+            })
+            .collect();
+        Ok((
+            format!(
+                "{:>5}: {:?} {}",
+                address,
+                instruction.opcode,
+                parameters.join(", ")
+            )
+            .trim_end()
+            .to_string(),
+            1 + instruction.opcode.parameter_count() as usize,
+        ))
+    }
+
+    /// Runs until the machine halts, needs more input than `inputs` provides, or has produced
+    /// `output_limit` outputs, returning the outputs and which of those stopped it.
+    pub fn run(
+        &mut self,
+        inputs: &[i64],
+        output_limit: Option<usize>,
+    ) -> Result<(Vec<i64>, StopReason)> {
+        let mut inputs = inputs.iter().copied();
+        let mut output = vec![];
+
+        let stop_reason = loop {
+            match self.step(&mut inputs)? {
+                Step::Continue => {}
+                Step::Output(value) => {
+                    output.push(value);
+                    if Some(output.len()) == output_limit {
+                        break StopReason::OutputLimit;
+                    }
+                }
+                Step::NeedInput => break StopReason::NeedInput,
+                Step::Halted => break StopReason::Halted,
+            }
+        };
+
+        Ok((output, stop_reason))
+    }
+
+    pub fn execute(&mut self, inputs: &[i64]) -> Result<Vec<i64>> {
+        self.run(inputs, None).map(|(output, _)| output)
+    }
+
+    /// Like `execute` with no inputs, but errors instead of running more than `max_steps`
+    /// instructions so programs that never halt can't hang the caller.
+    pub fn execute_n_steps(&mut self, max_steps: usize) -> Result<Vec<i64>> {
+        let mut inputs = iter::empty();
+        let mut output = vec![];
+        for _ in 0..max_steps {
+            match self.step(&mut inputs)? {
+                Step::Continue => {}
+                Step::Output(value) => output.push(value),
+                Step::NeedInput | Step::Halted => return Ok(output),
+            }
+        }
+        Err(From::from(format!(
+            "Intcode did not halt within {} steps",
+            max_steps
+        )))
+    }
+
+    /// Like `execute`, but errors with a `Timeout` instead of running past `deadline` of
+    /// wall-clock time. The clock is only checked every `DEADLINE_CHECK_INTERVAL` instructions.
+    pub fn execute_with_deadline(
+        &mut self,
+        inputs: &[i64],
+        deadline: Duration,
+    ) -> Result<Vec<i64>> {
+        let start = Instant::now();
+        let mut inputs = inputs.iter().copied();
+        let mut output = vec![];
+        let mut steps = 0;
+        loop {
+            match self.step(&mut inputs)? {
+                Step::Continue => {}
+                Step::Output(value) => output.push(value),
+                Step::NeedInput | Step::Halted => return Ok(output),
+            }
+            steps += 1;
+            if steps % DEADLINE_CHECK_INTERVAL == 0 && start.elapsed() > deadline {
+                return Err(Box::new(Timeout { deadline, steps }));
+            }
+        }
+    }
+
+    /// Runs until the machine halts, expecting at most one output. Errors if it produced more, or
+    /// if it paused for more input than `inputs` provides.
+    pub fn execute_once(&mut self, inputs: &[i64]) -> Result<Option<i64>> {
+        match self.run(inputs, None)? {
+            (_, StopReason::NeedInput) => {
+                Err(From::from("Intcode requested more input before halting"))
+            }
+            (output, _) => match output[..] {
+                [] => Ok(None),
+                [value] => Ok(Some(value)),
+                _ => Err(From::from(format!(
+                    "Expected at most one output, got {:?}",
+                    output
+                ))),
+            },
+        }
+    }
+
+    /// Runs until the machine halts, reading each input as a decimal integer on its own line of
+    /// `input` only when the program asks for it and writing each output on its own line of
+    /// `output`. Blank input lines are skipped.
+    pub fn execute_with_io<R: Read, W: Write>(&mut self, input: R, mut output: W) -> Result<()> {
+        let mut lines = io::BufReader::new(input).lines();
+        let mut next_input = None;
+        loop {
+            match self.step(&mut next_input.take().into_iter())? {
+                Step::Continue => {}
+                Step::Output(value) => writeln!(output, "{}", value)?,
+                Step::NeedInput => {
+                    let line = loop {
+                        match lines.next() {
+                            Some(line)
+                                if line.as_ref().is_ok_and(|line| line.trim().is_empty()) => {}
+                            Some(line) => break line?,
+                            None => {
+                                return Err(From::from("Input ended before the Intcode halted"))
+                            }
+                        }
+                    };
+                    next_input = Some(
+                        line.trim()
+                            .parse()
+                            .map_err(|err| format!("Invalid input {:?}: {}", line.trim(), err))?,
+                    );
+                }
+                Step::Halted => return Ok(()),
+            }
+        }
+    }
+
+    /// Runs until `n` outputs have been produced. Errors, listing the outputs it did produce, if
+    /// the machine halts or pauses for input first.
+    pub fn execute_n_outputs(&mut self, inputs: &[i64], n: usize) -> Result<Vec<i64>> {
+        match self.run(inputs, Some(n))? {
+            (output, StopReason::OutputLimit) => Ok(output),
+            (output, stop_reason) => Err(From::from(format!(
+                "Intcode {} after {} of {} outputs: {:?}",
+                match stop_reason {
+                    StopReason::NeedInput => "paused for input",
+                    _ => "halted",
+                },
+                output.len(),
+                n,
+                output
+            ))),
+        }
+    }
+
+    /// Serializes memory up to the highest written address back into comma-separated intcode,
+    /// filling any gaps with 0. Errors if that would be more than `MAX_SOURCE_ADDRESSES`.
+    pub fn to_source(&self) -> Result<String> {
+        let end = match self.integers.keys().max() {
+            Some(max_address) => max_address + 1,
+            None => return Ok(String::new()),
+        };
+        if end > MAX_SOURCE_ADDRESSES {
+            return Err(From::from(format!(
+                "Memory reaches address {}, too far to write out as source",
+                end - 1
+            )));
+        }
+        Ok((0..end)
+            .map(|address| self.integers.get(&address).unwrap_or(&0).to_string())
+            .collect::<Vec<String>>()
+            .join(","))
+    }
+}
+
+fn to_address(integer: i64) -> Result<usize> {
+    if integer < 0 {
+        return Err(From::from(format!("Invalid negative address {}", integer)));
+    }
+    Ok(integer as usize)
+}
+
+fn checked_add(a: i64, b: i64) -> Result<i64> {
+    a.checked_add(b)
+        .ok_or_else(|| From::from(format!("Overflow adding {} to {}", b, a)))
+}
+
+/// The line of `source` containing the `len` bytes at `start`, with carets underneath them.
+fn point_at(source: &str, start: usize, len: usize) -> String {
+    let line_start = source[..start].rfind('\n').map_or(0, |index| index + 1);
+    let line_end = source[start..]
+        .find('\n')
+        .map_or(source.len(), |index| start + index);
+    let line = source[line_start..line_end].trim_end_matches('\r');
+    let column = source[line_start..start].chars().count();
+    let width = source[start..start + len].chars().count();
+    format!("{}\n{}{}", line, " ".repeat(column), "^".repeat(width))
+}
+
+/// Converts an output value to a `u8` for the `TryFromPrimitive` enums, erroring instead of
+/// truncating values outside `0..=255`.
+pub fn narrow_u8(integer: i64) -> Result<u8> {
+    u8::try_from(integer)
+        .map_err(|_| From::from(format!("Output {} does not fit in a u8", integer)))
+}
+
+/// Parses comma-separated intcode source, for programs that don't live in a file.
+pub fn parse(s: &str) -> Result<Intcode> {
+    s.parse()
+}
+
+pub fn read_intcode(filename: &str) -> Result<Intcode> {
+    parse(&read_string(filename)?)
+}
+
+/// Interactively drives `intcode` from stdin. Numbers are queued as input, `step` executes one
+/// instruction, `mem <addr>` prints memory, `dis [count]` disassembles from the pointer, an empty
+/// line or `run` continues until input is needed, and `quit` exits.
+pub fn repl(intcode: Intcode) -> Result<()> {
+    let stdin = io::stdin();
+    run_repl(intcode, stdin.lock(), io::stdout())
+}
+
+fn run_repl(mut intcode: Intcode, input: impl BufRead, mut output: impl Write) -> Result<()> {
+    let mut queue: VecDeque<i64> = VecDeque::new();
+    for line in input.lines() {
+        match run_repl_command(&mut intcode, &mut queue, &line?, &mut output) {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(error) => writeln!(output, "Error: {}", error)?,
+        }
+    }
+    Ok(())
+}
+
+fn run_repl_command(
+    intcode: &mut Intcode,
+    queue: &mut VecDeque<i64>,
+    line: &str,
+    output: &mut impl Write,
+) -> Result<bool> {
+    let mut words = line.split_whitespace();
+    let mut single_step = false;
+    match words.next() {
+        Some("quit") | Some("exit") => return Ok(false),
+        Some("mem") => {
+            let address: usize = words.next().ok_or("Usage: mem <addr>")?.parse()?;
+            writeln!(output, "{}", intcode.integers.get(&address).unwrap_or(&0))?;
+            return Ok(true);
+        }
+        Some("dis") => {
+            let count: usize = words.next().unwrap_or("5").parse()?;
+            let mut address = intcode.pointer;
+            for _ in 0..count {
+                let (text, length) = intcode.disassemble(address)?;
+                writeln!(output, "{}", text)?;
+                address += length;
+            }
+            return Ok(true);
+        }
+        Some("step") => single_step = true,
+        Some("run") | None => {}
+        Some(_) => {
+            for word in line.split(|c: char| c == ',' || c.is_whitespace()) {
+                if !word.is_empty() {
+                    queue.push_back(word.parse()?);
+                }
+            }
+        }
+    }
+
+    loop {
+        match intcode.step(&mut iter::from_fn(|| queue.pop_front()))? {
+            Step::Continue => {}
+            Step::Output(value) => writeln!(output, "{}", value)?,
+            Step::NeedInput => {
+                writeln!(output, "Waiting for input")?;
+                break;
+            }
+            Step::Halted => {
+                writeln!(output, "Halted")?;
+                break;
+            }
+        }
+        if single_step {
+            break;
+        }
+    }
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    const TEST_INPUT: &str = "input/test1.txt";
+
+    #[test]
+    fn reads_intcode() {
+        assert_eq!(
+            read_intcode(TEST_INPUT).unwrap(),
+            Intcode::new(
+                vec![3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0]
+                    .into_iter()
+                    .enumerate()
+                    .collect()
+            ),
+        );
+    }
+
+    #[test]
+    fn converts_integer_to_instruction() {
+        assert_eq!(
+            Instruction::try_from(1002).unwrap(),
+            Instruction {
+                opcode: Opcode::Mult,
+                parameter_modes: vec![
+                    ParameterMode::Position,
+                    ParameterMode::Immediate,
+                    ParameterMode::Position
+                ],
+            }
+        );
+
+        assert_eq!(
+            Instruction::try_from(101).unwrap(),
+            Instruction {
+                opcode: Opcode::Add,
+                parameter_modes: vec![
+                    ParameterMode::Immediate,
+                    ParameterMode::Position,
+                    ParameterMode::Position
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn executes_intcodes() {
+        let mut intcode = Intcode::new(vec![1, 0, 0, 0, 99].into_iter().enumerate().collect());
+        intcode.execute(&[0]).unwrap();
+        assert_eq!(
+            intcode.integers,
+            vec![2, 0, 0, 0, 99].into_iter().enumerate().collect()
+        );
+
+        let mut intcode = Intcode::new(vec![2, 3, 0, 3, 99].into_iter().enumerate().collect());
+        intcode.execute(&[0]).unwrap();
+        assert_eq!(
+            intcode.integers,
+            vec![2, 3, 0, 6, 99].into_iter().enumerate().collect()
+        );
+
+        let mut intcode = Intcode::new(vec![2, 4, 4, 5, 99, 0].into_iter().enumerate().collect());
+        intcode.execute(&[0]).unwrap();
+        assert_eq!(
+            intcode.integers,
+            vec![2, 4, 4, 5, 99, 9801].into_iter().enumerate().collect()
+        );
+
+        let mut intcode = Intcode::new(
+            vec![1, 1, 1, 4, 99, 5, 6, 0, 99]
+                .into_iter()
+                .enumerate()
+                .collect(),
+        );
+        intcode.execute(&[0]).unwrap();
+        assert_eq!(
+            intcode.integers,
+            vec![30, 1, 1, 4, 2, 5, 6, 0, 99]
+                .into_iter()
+                .enumerate()
+                .collect()
+        );
+
+        let mut intcode = Intcode::new(
+            vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50]
+                .into_iter()
+                .enumerate()
+                .collect(),
+        );
+        intcode.execute(&[0]).unwrap();
+        assert_eq!(
+            intcode.integers,
+            vec![3500, 9, 10, 70, 2, 3, 11, 0, 99, 30, 40, 50]
+                .into_iter()
+                .enumerate()
+                .collect()
+        );
+    }
+
+    #[test]
+    fn less_and_equal_outputs() {
+        let intcode = Intcode::new(
+            vec![3, 9, 8, 9, 10, 9, 4, 9, 99, -1, 8]
+                .into_iter()
+                .enumerate()
+                .collect(),
+        );
+        assert_eq!(intcode.clone().execute(&[8]).unwrap(), vec![1]);
+        assert_eq!(intcode.clone().execute(&[0]).unwrap(), vec![0]);
+
+        let intcode = Intcode::new(
+            vec![3, 9, 7, 9, 10, 9, 4, 9, 99, -1, 8]
+                .into_iter()
+                .enumerate()
+                .collect(),
+        );
+        assert_eq!(intcode.clone().execute(&[0]).unwrap(), vec![1]);
+        assert_eq!(intcode.clone().execute(&[9]).unwrap(), vec![0]);
+
+        let intcode = Intcode::new(
+            vec![3, 3, 1108, -1, 8, 3, 4, 3, 99]
+                .into_iter()
+                .enumerate()
+                .collect(),
+        );
+        assert_eq!(intcode.clone().execute(&[8]).unwrap(), vec![1]);
+        assert_eq!(intcode.clone().execute(&[0]).unwrap(), vec![0]);
+
+        let intcode = Intcode::new(
+            vec![3, 3, 1107, -1, 8, 3, 4, 3, 99]
+                .into_iter()
+                .enumerate()
+                .collect(),
+        );
+        assert_eq!(intcode.clone().execute(&[0]).unwrap(), vec![1]);
+        assert_eq!(intcode.clone().execute(&[9]).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn jump_outputs() {
+        let intcode = Intcode::new(
+            vec![3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9]
+                .into_iter()
+                .enumerate()
+                .collect(),
+        );
+        assert_eq!(intcode.clone().execute(&[0]).unwrap(), vec![0]);
+        assert_eq!(intcode.clone().execute(&[1]).unwrap(), vec![1]);
+
+        let intcode = Intcode::new(
+            vec![3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1]
+                .into_iter()
+                .enumerate()
+                .collect(),
+        );
+        assert_eq!(intcode.clone().execute(&[0]).unwrap(), vec![0]);
+        assert_eq!(intcode.clone().execute(&[1]).unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn larger_part2_intcode() {
+        let intcode = Intcode::new(
+            vec![
+                3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36,
+                98, 0, 0, 1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000,
+                1, 20, 4, 20, 1105, 1, 46, 98, 99,
+            ]
+            .into_iter()
+            .enumerate()
+            .collect(),
+        );
+        assert_eq!(intcode.clone().execute(&[0]).unwrap(), vec![999]);
+        assert_eq!(intcode.clone().execute(&[8]).unwrap(), vec![1000]);
+        assert_eq!(intcode.clone().execute(&[9]).unwrap(), vec![1001]);
+    }
+
+    #[test]
+    fn multiple_input_intcode() {
+        let intcode = Intcode::new(
+            vec![
+                3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0,
+            ]
+            .into_iter()
+            .enumerate()
+            .collect(),
+        );
+        assert_eq!(intcode.clone().execute(&[1, 1]).unwrap(), vec![11]);
+    }
+
+    #[test]
+    fn relative_base_offset_quine() {
+        let code = vec![
+            109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+        ];
+        let intcode = Intcode::new(code.clone().into_iter().enumerate().collect());
+        assert_eq!(intcode.clone().execute(&[]).unwrap(), code);
+    }
+
+    #[test]
+    fn sixteen_digit_output() {
+        let code = vec![1102, 34915192, 34915192, 7, 4, 7, 99, 0];
+        let intcode = Intcode::new(code.into_iter().enumerate().collect());
+        assert_eq!(intcode.clone().execute(&[]).unwrap(), [1219070632396864]);
+    }
+
+    #[test]
+    fn large_output() {
+        let code = vec![104, 1125899906842624, 99];
+        let intcode = Intcode::new(code.into_iter().enumerate().collect());
+        assert_eq!(intcode.clone().execute(&[]).unwrap(), [1125899906842624]);
+    }
+
+    #[test]
+    fn disassembles_instructions() {
+        let intcode = Intcode::new(
+            vec![1002, 4, 3, 4, 33, 109, -1, 204, 2, 99]
+                .into_iter()
+                .enumerate()
+                .collect(),
+        );
+        assert_eq!(
+            intcode.disassemble(0).unwrap(),
+            ("    0: Mult [4], 3, [4]".to_string(), 4)
+        );
+        assert_eq!(
+            intcode.disassemble(5).unwrap(),
+            ("    5: RelativeBaseOffset -1".to_string(), 2)
+        );
+        assert_eq!(
+            intcode.disassemble(7).unwrap(),
+            ("    7: Output [rb+2]".to_string(), 2)
+        );
+        assert_eq!(
+            intcode.disassemble(9).unwrap(),
+            ("    9: Halt".to_string(), 1)
+        );
+        assert!(intcode.disassemble(4).is_err());
+    }
+
+    #[test]
+    fn drives_intcode_from_repl_commands() {
+        let intcode = Intcode::new(
+            vec![3, 9, 4, 9, 99, 0, 0, 0, 0, 0]
+                .into_iter()
+                .enumerate()
+                .collect(),
+        );
+        let commands = "dis 2\nrun\nmem 9\n42\nmem 9\nstep\nstep\nbogus\n";
+        let mut output = vec![];
+        run_repl(intcode, io::Cursor::new(commands), &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            concat!(
+                "    0: Input [9]\n",
+                "    2: Output [9]\n",
+                "Waiting for input\n",
+                "0\n",
+                "42\n",
+                "Halted\n",
+                "42\n",
+                "Halted\n",
+                "Halted\n",
+                "Error: invalid digit found in string\n",
+            )
+        );
+    }
+
+    #[test]
+    fn counts_executed_opcodes() {
+        let mut intcode = Intcode::new(vec![1, 0, 0, 0, 99].into_iter().enumerate().collect());
+        intcode.execute(&[]).unwrap();
+        assert!(intcode.opcode_counts().is_empty());
+
+        let mut intcode = Intcode::new(vec![1, 0, 0, 0, 99].into_iter().enumerate().collect());
+        intcode.count_opcodes = true;
+        intcode.execute(&[]).unwrap();
+        assert_eq!(
+            intcode.opcode_counts(),
+            &vec![(Opcode::Add, 1), (Opcode::Halt, 1)]
+                .into_iter()
+                .collect()
+        );
+    }
+
+    #[test]
+    fn reports_stop_reasons() {
+        let code = vec![3, 9, 104, 1, 104, 2, 99];
+        let mut intcode = Intcode::new(code.into_iter().enumerate().collect());
+        assert_eq!(
+            intcode.run(&[], None).unwrap(),
+            (vec![], StopReason::NeedInput)
+        );
+        assert_eq!(
+            intcode.run(&[5], Some(1)).unwrap(),
+            (vec![1], StopReason::OutputLimit)
+        );
+        assert_eq!(
+            intcode.run(&[], None).unwrap(),
+            (vec![2], StopReason::Halted)
+        );
+    }
+
+    #[test]
+    fn dumps_memory_to_source() {
+        let code = vec![1, 0, 0, 0, 99];
+        let mut intcode = Intcode::new(code.into_iter().enumerate().collect());
+        assert_eq!(intcode.to_source().unwrap(), "1,0,0,0,99");
+        intcode.execute(&[]).unwrap();
+        assert_eq!(intcode.to_source().unwrap(), "2,0,0,0,99");
+
+        let mut intcode: Intcode = "3,7,99".parse().unwrap();
+        intcode.execute(&[5]).unwrap();
+        assert_eq!(intcode.to_source().unwrap(), "3,7,99,0,0,0,0,5");
+        assert_eq!(
+            intcode
+                .to_source()
+                .unwrap()
+                .parse::<Intcode>()
+                .unwrap()
+                .to_source()
+                .unwrap(),
+            intcode.to_source().unwrap()
+        );
+    }
+
+    #[test]
+    fn refuses_to_dump_sparse_memory() {
+        let mut intcode: Intcode = "1101,0,0,1000000000000,99".parse().unwrap();
+        intcode.execute(&[]).unwrap();
+        assert_eq!(
+            intcode.to_source().unwrap_err().to_string(),
+            "Memory reaches address 1000000000000, too far to write out as source"
+        );
+        assert!(fmt::write(&mut String::new(), format_args!("{}", intcode)).is_err());
+    }
+
+    #[test]
+    fn diffs_memory() {
+        let original = Intcode::from_slice(&[1, 0, 0, 0, 99]);
+        let mut intcode = original.clone();
+        assert_eq!(original.diff(&intcode), vec![]);
+        intcode.execute(&[]).unwrap();
+        assert_eq!(original.diff(&intcode), vec![(0, 1, 2)]);
+        assert_eq!(intcode.diff(&original), vec![(0, 2, 1)]);
+
+        let mut intcode: Intcode = "3,7,99".parse().unwrap();
+        intcode.execute(&[5]).unwrap();
+        assert_eq!(
+            "3,7,99".parse::<Intcode>().unwrap().diff(&intcode),
+            vec![(7, 0, 5)]
+        );
+    }
+
+    #[test]
+    fn executes_until_n_outputs() {
+        let code = vec![104, 1, 104, 2, 104, 3, 99];
+        let mut intcode = Intcode::new(code.clone().into_iter().enumerate().collect());
+        assert_eq!(intcode.execute_n_outputs(&[], 2).unwrap(), vec![1, 2]);
+        assert_eq!(
+            intcode.execute_n_outputs(&[], 2).unwrap_err().to_string(),
+            "Intcode halted after 1 of 2 outputs: [3]"
+        );
+        assert!(intcode.halted);
+
+        let mut intcode = Intcode::new(code.into_iter().enumerate().collect());
+        assert_eq!(intcode.execute_n_outputs(&[], 3).unwrap(), vec![1, 2, 3]);
+        assert!(!intcode.halted);
+
+        let code = vec![3, 9, 4, 9, 104, 7, 1105, 1, 0, 0];
+        let mut intcode = Intcode::new(code.into_iter().enumerate().collect());
+        assert_eq!(intcode.execute_n_outputs(&[5], 2).unwrap(), vec![5, 7]);
+        assert_eq!(
+            intcode.execute_n_outputs(&[], 2).unwrap_err().to_string(),
+            "Intcode paused for input after 0 of 2 outputs: []"
+        );
+        assert!(!intcode.halted);
+    }
+
+    #[test]
+    fn relative_target_parameters() {
+        let code = vec![109, 1, 203, 2, 204, 2, 99];
+        let intcode = Intcode::new(code.into_iter().enumerate().collect());
+        assert_eq!(intcode.clone().execute(&[123]).unwrap(), [123]);
+    }
+
+    #[test]
+    fn parses_empty_program() {
+        let intcode: Intcode = "".parse().unwrap();
+        assert_eq!(intcode, Intcode::from_slice(&[]));
+        assert_eq!(intcode.to_string(), "");
+        assert_eq!(
+            intcode.memory_stats(),
+            MemoryStats {
+                cells_written: 0,
+                highest_address: None,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_source() {
+        assert_eq!(
+            "1,0,x,0,99".parse::<Intcode>().unwrap_err().to_string(),
+            "Invalid number \"x\": invalid digit found in string\n\
+             1,0,x,0,99\n    ^"
+        );
+        assert_eq!(
+            "1, 0,  1o1 ,0,99\n"
+                .parse::<Intcode>()
+                .unwrap_err()
+                .to_string(),
+            "Invalid number \"1o1\": invalid digit found in string\n\
+             1, 0,  1o1 ,0,99\n       ^^^"
+        );
+        assert_eq!(
+            "1,,0,0,99".parse::<Intcode>().unwrap_err().to_string(),
+            "Missing number\n1,,0,0,99\n  ^"
+        );
+        assert_eq!(
+            "1,0,99,".parse::<Intcode>().unwrap_err().to_string(),
+            "Missing number\n1,0,99,\n      ^"
+        );
+        assert_eq!("1,0,99\n".parse::<Intcode>().unwrap().integers.len(), 3);
+        // only the offending line of multi-line source is shown
+        assert_eq!(
+            "1,0,\n0,99999999999999999999,\n99"
+                .parse::<Intcode>()
+                .unwrap_err()
+                .to_string(),
+            "Invalid number \"99999999999999999999\": number too large to fit in target type\n\
+             0,99999999999999999999,\n  ^^^^^^^^^^^^^^^^^^^^"
+        );
+    }
+
+    #[test]
+    fn executes_n_steps() {
+        let mut intcode: Intcode = "104,7,99".parse().unwrap();
+        assert_eq!(intcode.execute_n_steps(1000).unwrap(), vec![7]);
+
+        let mut intcode: Intcode = "1105,1,0".parse().unwrap();
+        assert_eq!(
+            intcode.execute_n_steps(1000).unwrap_err().to_string(),
+            "Intcode did not halt within 1000 steps"
+        );
+    }
+
+    #[test]
+    fn executes_once() {
+        let mut intcode: Intcode = "3,100,99".parse().unwrap();
+        assert_eq!(intcode.execute_once(&[7]).unwrap(), None);
+
+        let mut intcode: Intcode = "3,100,4,100,99".parse().unwrap();
+        assert_eq!(intcode.execute_once(&[42]).unwrap(), Some(42));
+        assert!(intcode.halted);
+
+        let mut intcode: Intcode = "104,1,104,2,99".parse().unwrap();
+        assert_eq!(
+            intcode.execute_once(&[]).unwrap_err().to_string(),
+            "Expected at most one output, got [1, 2]"
+        );
+
+        let mut intcode: Intcode = "104,1,3,100,99".parse().unwrap();
+        assert_eq!(
+            intcode.execute_once(&[]).unwrap_err().to_string(),
+            "Intcode requested more input before halting"
+        );
+    }
+
+    #[test]
+    fn executes_with_io() {
+        let mut output = vec![];
+        let mut intcode: Intcode = "3,0,4,0,99".parse().unwrap();
+        intcode
+            .execute_with_io(io::Cursor::new(&b"42\n"[..]), &mut output)
+            .unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "42\n");
+
+        // equal to 8, reading a later input only once it is needed
+        let program = "3,13,8,13,14,13,4,13,3,13,4,13,99,-1,8";
+        let mut output = vec![];
+        let mut intcode: Intcode = program.parse().unwrap();
+        intcode
+            .execute_with_io(io::Cursor::new(&b"8\n\n-7\n"[..]), &mut output)
+            .unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "1\n-7\n");
+
+        let mut intcode: Intcode = program.parse().unwrap();
+        assert_eq!(
+            intcode
+                .execute_with_io(io::Cursor::new(&b"8\n"[..]), vec![])
+                .unwrap_err()
+                .to_string(),
+            "Input ended before the Intcode halted"
+        );
+        let mut intcode: Intcode = program.parse().unwrap();
+        assert_eq!(
+            intcode
+                .execute_with_io(io::Cursor::new(&b"eight\n"[..]), vec![])
+                .unwrap_err()
+                .to_string(),
+            "Invalid input \"eight\": invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn times_out_spinning_programs() {
+        let mut intcode: Intcode = "3,100,4,100,99".parse().unwrap();
+        assert_eq!(
+            intcode
+                .execute_with_deadline(&[42], Duration::from_secs(10))
+                .unwrap(),
+            vec![42]
+        );
+
+        let mut intcode: Intcode = "1105,1,0".parse().unwrap();
+        let deadline = Duration::from_millis(20);
+        let start = Instant::now();
+        let error = intcode.execute_with_deadline(&[], deadline).unwrap_err();
+        assert!(start.elapsed() < Duration::from_secs(5));
+        let timeout = error.downcast_ref::<Timeout>().unwrap();
+        assert_eq!(timeout.deadline, deadline);
+        assert_eq!(timeout.steps % DEADLINE_CHECK_INTERVAL, 0);
+    }
+
+    #[test]
+    fn errors_on_malformed_programs() {
+        let error = |source: &str| {
+            let mut intcode: Intcode = source.parse().unwrap();
+            intcode.execute_n_steps(1000).unwrap_err().to_string()
+        };
+        assert_eq!(error("1,-1,0,0,99"), "Invalid negative address -1");
+        assert_eq!(error("1101,1,1,-3,99"), "Invalid negative address -3");
+        assert_eq!(error("1106,0,-2"), "Invalid negative address -2");
+        assert_eq!(error("204,-5,99"), "Invalid negative address -5");
+        assert_eq!(
+            error("1101,9223372036854775807,1,0,99"),
+            "Overflow adding 1 to 9223372036854775807"
+        );
+        assert_eq!(
+            error("1102,9223372036854775807,2,0,99"),
+            "Overflow multiplying 9223372036854775807 by 2"
+        );
+        assert_eq!(
+            error("109,9223372036854775807,109,1,99"),
+            "Overflow adding 1 to 9223372036854775807"
+        );
+        // running off the end of the program reads an invalid opcode 0
+        assert_eq!(
+            error("1101,1,1,5"),
+            "No discriminant in enum `Opcode` matches the value `0`"
+        );
+    }
+
+    #[test]
+    fn narrows_outputs_to_u8() {
+        assert_eq!(narrow_u8(0).unwrap(), 0);
+        assert_eq!(narrow_u8(255).unwrap(), 255);
+        assert_eq!(
+            narrow_u8(256).unwrap_err().to_string(),
+            "Output 256 does not fit in a u8"
+        );
+        assert_eq!(
+            narrow_u8(-1).unwrap_err().to_string(),
+            "Output -1 does not fit in a u8"
+        );
+    }
+
+    prop_compose! {
+        fn arb_intcode_program()(
+            program in vec(i64::MIN..=i64::MAX, 1..=1000)
+        ) -> Vec<i64> {
+            program
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips_through_source(program in arb_intcode_program()) {
+            let intcode = Intcode::from_slice(&program);
+            prop_assert_eq!(intcode.to_string().parse::<Intcode>().unwrap(), intcode);
+        }
+
+        #[test]
+        fn counts_cells_written(program in arb_intcode_program()) {
+            let stats = Intcode::from_slice(&program).memory_stats();
+            prop_assert_eq!(stats.cells_written, program.len());
+            prop_assert_eq!(stats.highest_address, Some(program.len() - 1));
+        }
+    }
+}